@@ -8,17 +8,23 @@ use quote::quote;
 use std::str::FromStr;
 use strum::{Display, EnumString};
 use syn::fold::Fold;
+use syn::meta::ParseNestedMeta;
+use syn::parse::Parser;
 use syn::WhereClause;
 use syn::{
-    parse2, parse_quote, parse_str, punctuated::Punctuated, token::Comma, Block, FnArg,
-    GenericArgument, GenericParam, Generics, Ident, ItemFn, Lifetime, Pat, PatIdent, PatType,
-    PathArguments, Signature, Stmt, Type, TypePath, WherePredicate,
+    parse2, parse_quote, parse_str,
+    punctuated::Punctuated,
+    token::{Comma, Plus},
+    AngleBracketedGenericArguments, Attribute, Block, Expr, FnArg, GenericArgument, GenericParam,
+    Generics, Ident, ItemFn, Lifetime, Pat, PatIdent, PatType, Path, PathArguments, PathSegment,
+    ReturnType, Signature, Stmt, Type, TypeParamBound, TypePath, WherePredicate,
 };
 
 pub fn anyinput_core(args: TokenStream, input: TokenStream) -> TokenStream {
-    if !args.is_empty() {
-        abort!(args, "anyinput does not take any arguments.")
-    }
+    let options = match Options::parse(args) {
+        Ok(options) => options,
+        Err(error) => return error.to_compile_error(),
+    };
 
     // proc_marco2 version of "parse_macro_input!(input as ItemFn)"
     let old_item_fn = match parse2::<ItemFn>(input) {
@@ -26,7 +32,7 @@ pub fn anyinput_core(args: TokenStream, input: TokenStream) -> TokenStream {
         Err(error) => return error.to_compile_error(),
     };
 
-    let new_item_fn = transform_fn(old_item_fn);
+    let new_item_fn = transform_fn(old_item_fn, &options);
 
     quote!(#new_item_fn)
 }
@@ -57,9 +63,11 @@ fn transform_fn_sample(_item_fn: ItemFn) -> ItemFn {
     }
 }
 
-fn transform_fn(item_fn: ItemFn) -> ItemFn {
+fn transform_fn(item_fn: ItemFn, options: &Options) -> ItemFn {
+    check_for_shadowed_specials(&item_fn, options);
     let mut suffix_iter = simple_suffix_iter_factory();
-    let delta_fn_arg_new = |fn_arg| DeltaFnArg::new(fn_arg, &mut suffix_iter);
+    let output = &item_fn.sig.output;
+    let delta_fn_arg_new = |fn_arg| DeltaFnArg::new(fn_arg, options, output, &mut suffix_iter);
 
     // Transform each old argument of the function, accumulating: the new argument, new generics, wheres, and statements
     // Then, turn the accumulation into a new function.
@@ -72,16 +80,57 @@ fn transform_fn(item_fn: ItemFn) -> ItemFn {
         .to_item_fn()
 }
 
+// If the function has a generic with the name of a special, for example, "fn f<AnyPath>(..)",
+// the user surely means their type, so abort unless the special is turned off for every input.
+// (Items in the function body aren't in scope in the signature, so they can't shadow a special.)
+fn check_for_shadowed_specials(item_fn: &ItemFn, options: &Options) {
+    // Bad input options are reported later, when the input is transformed.
+    let input_options: Vec<Options> = item_fn
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|fn_arg| match fn_arg {
+            FnArg::Typed(pat_type) => options.with_input_attrs(&pat_type.attrs).ok(),
+            FnArg::Receiver(_) => None,
+        })
+        .collect();
+    let generic_idents = item_fn
+        .sig
+        .generics
+        .params
+        .iter()
+        .filter_map(|generic_param| match generic_param {
+            GenericParam::Type(type_param) => Some(&type_param.ident),
+            GenericParam::Lifetime(_) | GenericParam::Const(_) => None,
+        });
+    for ident in generic_idents {
+        if let Some(special) = input_options
+            .iter()
+            .find_map(|options| options.maybe_special(ident))
+        {
+            abort!(
+                ident,
+                "'{}' is defined here, but it is also the name of a special. To use your own '{}', write #[anyinput(except({}))].",
+                ident,
+                ident,
+                special
+            )
+        }
+    }
+}
+
 struct ItemFnAcc<'a> {
     old_fn: &'a ItemFn,
     fn_args: Punctuated<FnArg, Comma>,
     generic_params: Punctuated<GenericParam, Comma>,
     where_predicates: Punctuated<WherePredicate, Comma>,
     stmts: Vec<Stmt>,
+    added_stmt_count: usize, // the number of statements added to the start of the old function's statements
+    marker_types: Vec<Type>, // the marker types named by the inputs, for example, "AnyIter<()>" (see marker_stmt)
 }
 
 impl ItemFnAcc<'_> {
-    fn init(item_fn: &ItemFn) -> ItemFnAcc {
+    fn init(item_fn: &ItemFn) -> ItemFnAcc<'_> {
         // Start with 1. no function arguments, 2. the old function's generics, wheres, and statements
         ItemFnAcc {
             old_fn: item_fn,
@@ -89,6 +138,8 @@ impl ItemFnAcc<'_> {
             generic_params: item_fn.sig.generics.params.clone(),
             where_predicates: ItemFnAcc::extract_where_predicates(item_fn),
             stmts: item_fn.block.stmts.clone(),
+            added_stmt_count: 0,
+            marker_types: vec![],
         }
     }
 
@@ -105,12 +156,46 @@ impl ItemFnAcc<'_> {
         self.fn_args.push(delta.fn_arg);
         self.generic_params.extend(delta.generic_params);
         self.where_predicates.extend(delta.where_predicates);
-        for (index, element) in delta.stmt.into_iter().enumerate() {
-            self.stmts.insert(index, element);
+        // A statement goes first, unless it is late. A late statement goes after those added so far.
+        if let Some(stmt) = delta.stmt {
+            let index = if delta.stmt_is_late {
+                self.added_stmt_count
+            } else {
+                0
+            };
+            self.stmts.insert(index, stmt);
+            self.added_stmt_count += 1;
+        }
+        for marker_type in delta.marker_types {
+            if !self.marker_types.contains(&marker_type) {
+                self.marker_types.push(marker_type);
+            }
         }
         self
     }
 
+    // The macro erases the names of specials, so an import of a marker, for example,
+    // "use anyinput::AnyString;", would be reported as unused. This statement keeps a use of each marker named.
+    // The inner "self::*" finds the module's own import, if any. Otherwise, the outer "anyinput::*" supplies
+    // the marker. (In type position, these globs may shadow outer names without ambiguity.)
+    fn marker_stmt(&self) -> Option<Stmt> {
+        if self.marker_types.is_empty() {
+            return None;
+        }
+        let marker_types = &self.marker_types;
+        Some(parse_quote! {
+            {
+                #[allow(unused_imports)]
+                use anyinput::*;
+                {
+                    #[allow(unused_imports)]
+                    use self::*;
+                    let _: ::core::marker::PhantomData<(#(#marker_types,)*)>;
+                }
+            }
+        })
+    }
+
     // Use Rust's struct update syntax (https://www.reddit.com/r/rust/comments/pchp8h/media_struct_update_syntax_in_rust/)
     fn to_item_fn(&self) -> ItemFn {
         ItemFn {
@@ -120,7 +205,11 @@ impl ItemFnAcc<'_> {
                 ..self.old_fn.sig.clone()
             },
             block: Box::new(Block {
-                stmts: self.stmts.clone(),
+                stmts: self
+                    .marker_stmt()
+                    .into_iter()
+                    .chain(self.stmts.clone())
+                    .collect(),
                 ..*self.old_fn.block.clone()
             }),
             ..self.old_fn.clone()
@@ -148,6 +237,114 @@ impl ItemFnAcc<'_> {
     }
 }
 
+// The options given to the macro, for example, "#[anyinput(send, static)]".
+// They can be given for the whole function or, as an attribute, for a single input.
+#[derive(Debug, Clone, Default)]
+struct Options {
+    send: bool,                     // add "Send + Sync" to every generated bound
+    is_static: bool,                // add "'static" to every generated bound
+    only: Option<Vec<Special>>,     // if given, the only specials to replace
+    except: Vec<Special>,           // specials not to replace
+    aliases: Vec<(Ident, Special)>, // other names for specials, for example, "Text = AnyString"
+    len: Option<Expr>, // the length that AnyRange resolves against, for example, "data.len()"
+}
+
+impl Options {
+    fn parse(args: TokenStream) -> syn::Result<Options> {
+        let mut options = Options::default();
+        syn::meta::parser(|meta| options.parse_meta(meta)).parse2(args)?;
+        Ok(options)
+    }
+
+    fn parse_meta(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("send") {
+            self.send = true;
+        } else if meta.path.is_ident("static") {
+            self.is_static = true;
+        } else if meta.path.is_ident("only") {
+            let only = self.only.get_or_insert_with(Vec::new);
+            meta.parse_nested_meta(|meta| {
+                only.push(Special::from_meta_path(&meta)?);
+                Ok(())
+            })?;
+        } else if meta.path.is_ident("except") {
+            meta.parse_nested_meta(|meta| {
+                self.except.push(Special::from_meta_path(&meta)?);
+                Ok(())
+            })?;
+        } else if meta.path.is_ident("len") {
+            self.len = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("alias") {
+            meta.parse_nested_meta(|meta| {
+                let alias = meta.path.require_ident()?.clone();
+                let special = Special::from_ident(&meta.value()?.parse()?)?;
+                self.aliases.push((alias, special));
+                Ok(())
+            })?;
+        } else {
+            let path = &meta.path;
+            let name = quote!(#path).to_string();
+            return Err(meta.error(format!("Unknown anyinput option '{name}'.")));
+        }
+        Ok(())
+    }
+
+    // Start with the function's options and add those of an input, for example,
+    // "#[anyinput(send)] iter: AnyIter<usize>".
+    fn with_input_attrs(&self, attrs: &[Attribute]) -> syn::Result<Options> {
+        let mut options = self.clone();
+        for attr in attrs.iter().filter(|attr| is_anyinput_attr(attr)) {
+            attr.parse_nested_meta(|meta| options.parse_meta(meta))?;
+        }
+        Ok(options)
+    }
+
+    // Find the special named by an identifier, for example, "AnyString" or an alias such as "Text".
+    // Return None if the identifier names no special or if the special is not enabled.
+    fn maybe_special(&self, ident: &Ident) -> Option<Special> {
+        let special = self
+            .aliases
+            .iter()
+            .find(|(alias, _)| alias == ident)
+            .map(|(_, special)| special.clone())
+            .or_else(|| Special::from_str(ident.to_string().as_ref()).ok())?;
+        if self.is_enabled(&special) {
+            Some(special)
+        } else {
+            None
+        }
+    }
+
+    // Tells if the macro should replace a special, given "only(..)" and "except(..)".
+    fn is_enabled(&self, special: &Special) -> bool {
+        let is_in_only = match &self.only {
+            Some(only) => only.contains(special),
+            None => true,
+        };
+        is_in_only && !self.except.contains(special)
+    }
+
+    // The bounds to add to every generated bound, for example, "Send + Sync + 'static".
+    fn extra_bounds(&self) -> Bounds {
+        let mut bounds = Bounds::new();
+        if self.send {
+            bounds.push(parse_quote!(Send));
+            bounds.push(parse_quote!(Sync));
+        }
+        if self.is_static {
+            bounds.push(parse_quote!('static));
+        }
+        bounds
+    }
+}
+
+// A list of bounds, for example, "ExactSizeIterator + Clone".
+type Bounds = Punctuated<TypeParamBound, Plus>;
+
+fn is_anyinput_attr(attr: &Attribute) -> bool {
+    attr.path().is_ident("anyinput")
+}
+
 // Define a generator for suffixes of generic types. "0", "1", "2", ...
 // This is used to create unique names for generic types.
 // Could switch to one based on UUIDs, but this is easier to read.
@@ -156,7 +353,8 @@ fn simple_suffix_iter_factory() -> impl Iterator<Item = String> + 'static {
 }
 
 // Define the Specials and their properties.
-#[derive(Debug, Clone, EnumString, Display)]
+// Each special has a documented marker type of the same name in the anyinput crate.
+#[derive(Debug, Clone, PartialEq, EnumString, Display)]
 #[allow(clippy::enum_variant_names)]
 enum Special {
     AnyArray,
@@ -164,6 +362,29 @@ enum Special {
     AnyPath,
     AnyIter,
     AnyNdArray,
+    AnyBytes,
+    AnyOsStr,
+    AnyCStr,
+    AnyArrayMut,
+    AnyStringMut,
+    AnyNdArrayMut,
+    AnyCow,
+    AnyInto,
+    AnyTryInto,
+    AnyOption,
+    AnyMap,
+    AnySet,
+    AnyRange,
+    AnyIterBorrow,
+    AnyIterMulti,
+    AnyTryIter,
+    AnyOneOrMany,
+    AnyDisplay,
+    AnyDuration,
+    AnyArc,
+    AnyError,
+    AnyAnyhow,
+    AnySocketAddr,
 }
 
 impl Special {
@@ -197,6 +418,39 @@ impl Special {
                     #generic : AsRef<std::path::Path>
                 }
             }
+            Special::AnyBytes => {
+                if maybe_sub_type.is_some() {
+                    abort!(span_range,"AnyBytes should not have a generic parameter, so 'AnyBytes', not 'AnyBytes<_>'.")
+                };
+                if maybe_lifetime.is_some() {
+                    abort!(span_range, "AnyBytes should not have a lifetime.")
+                };
+                parse_quote! {
+                    #generic : AsRef<[u8]>
+                }
+            }
+            Special::AnyOsStr => {
+                if maybe_sub_type.is_some() {
+                    abort!(span_range,"AnyOsStr should not have a generic parameter, so 'AnyOsStr', not 'AnyOsStr<_>'.")
+                };
+                if maybe_lifetime.is_some() {
+                    abort!(span_range, "AnyOsStr should not have a lifetime.")
+                };
+                parse_quote! {
+                    #generic : AsRef<std::ffi::OsStr>
+                }
+            }
+            Special::AnyCStr => {
+                if maybe_sub_type.is_some() {
+                    abort!(span_range,"AnyCStr should not have a generic parameter, so 'AnyCStr', not 'AnyCStr<_>'.")
+                };
+                if maybe_lifetime.is_some() {
+                    abort!(span_range, "AnyCStr should not have a lifetime.")
+                };
+                parse_quote! {
+                    #generic : anyinput::ToCStr
+                }
+            }
             Special::AnyArray => {
                 let sub_type = match maybe_sub_type {
                     Some(sub_type) => sub_type,
@@ -238,61 +492,865 @@ impl Special {
                     #generic: Into<ndarray::ArrayView1<#lifetime, #sub_type>>
                 }
             }
+            Special::AnyStringMut => {
+                if maybe_sub_type.is_some() {
+                    abort!(span_range,"AnyStringMut should not have a generic parameter, so 'AnyStringMut', not 'AnyStringMut<_>'.")
+                };
+                if maybe_lifetime.is_some() {
+                    abort!(span_range, "AnyStringMut should not have a lifetime.")
+                };
+                parse_quote! {
+                    #generic : AsMut<str>
+                }
+            }
+            Special::AnyArrayMut => {
+                let sub_type = match maybe_sub_type {
+                    Some(sub_type) => sub_type,
+                    None => {
+                        abort!(span_range,"AnyArrayMut expects a generic parameter, for example, AnyArrayMut<usize>.")
+                    }
+                };
+                if maybe_lifetime.is_some() {
+                    abort!(span_range, "AnyArrayMut should not have a lifetime.")
+                };
+                parse_quote! {
+                    #generic : AsMut<[#sub_type]>
+                }
+            }
+            Special::AnyNdArrayMut => {
+                let sub_type = match maybe_sub_type {
+                    Some(sub_type) => sub_type,
+                    None => {
+                        abort!(span_range,"AnyNdArrayMut expects a generic parameter, for example, AnyNdArrayMut<f32>.")
+                    }
+                };
+                let lifetime = maybe_lifetime
+                    .expect("Internal error: AnyNdArrayMut should be given a lifetime.");
+                parse_quote! {
+                    #generic: Into<ndarray::ArrayViewMut1<#lifetime, #sub_type>>
+                }
+            }
+            Special::AnyCow => {
+                let sub_type = match maybe_sub_type {
+                    Some(sub_type) => sub_type,
+                    None => {
+                        abort!(span_range,"AnyCow expects a generic parameter, for example, AnyCow<str>, AnyCow<Path>, or AnyCow<[usize]>.")
+                    }
+                };
+                let lifetime =
+                    maybe_lifetime.expect("Internal error: AnyCow should be given a lifetime.");
+                parse_quote! {
+                    #generic: Into<std::borrow::Cow<#lifetime, #sub_type>>
+                }
+            }
+            Special::AnyInto => {
+                let sub_type = match maybe_sub_type {
+                    Some(sub_type) => sub_type,
+                    None => {
+                        abort!(
+                            span_range,
+                            "AnyInto expects a generic parameter, for example, AnyInto<Config>."
+                        )
+                    }
+                };
+                if maybe_lifetime.is_some() {
+                    abort!(span_range, "AnyInto should not have a lifetime.")
+                };
+                parse_quote! {
+                    #generic : Into<#sub_type>
+                }
+            }
+            Special::AnyTryInto => {
+                let sub_type = match maybe_sub_type {
+                    Some(sub_type) => sub_type,
+                    None => {
+                        abort!(
+                            span_range,
+                            "AnyTryInto expects a generic parameter, for example, AnyTryInto<u16>."
+                        )
+                    }
+                };
+                if maybe_lifetime.is_some() {
+                    abort!(span_range, "AnyTryInto should not have a lifetime.")
+                };
+                parse_quote! {
+                    #generic : TryInto<#sub_type>
+                }
+            }
+            Special::AnyOption => {
+                let sub_type = match maybe_sub_type {
+                    Some(sub_type) => sub_type,
+                    None => {
+                        abort!(span_range,"AnyOption expects a generic parameter, for example, AnyOption<usize> or AnyOption<AnyString>.")
+                    }
+                };
+                if maybe_lifetime.is_some() {
+                    abort!(span_range, "AnyOption should not have a lifetime.")
+                };
+                parse_quote! {
+                    #generic : Into<Option<#sub_type>>
+                }
+            }
+            // The sub type is the tuple "(K, V)". (See create_pair_sub_type_and_bounds.)
+            Special::AnyMap => {
+                let sub_type = match maybe_sub_type {
+                    Some(sub_type) => sub_type,
+                    None => {
+                        abort!(span_range,"AnyMap expects a key type and a value type, for example, AnyMap<AnyString, usize> or AnyMap<AnyString, AnyPath>.")
+                    }
+                };
+                if maybe_lifetime.is_some() {
+                    abort!(span_range, "AnyMap should not have a lifetime.")
+                };
+                parse_quote! {
+                    #generic : IntoIterator<Item = #sub_type>
+                }
+            }
+            Special::AnySet => {
+                let sub_type = match maybe_sub_type {
+                    Some(sub_type) => sub_type,
+                    None => {
+                        abort!(
+                            span_range,
+                            "AnySet expects a generic parameter, for example, AnySet<usize>."
+                        )
+                    }
+                };
+                if maybe_lifetime.is_some() {
+                    abort!(span_range, "AnySet should not have a lifetime.")
+                };
+                parse_quote! {
+                    #generic : anyinput::IntoSet<#sub_type>
+                }
+            }
+            // The index type defaults to usize.
+            Special::AnyRange => {
+                let sub_type = maybe_sub_type.unwrap_or_else(|| parse_quote!(usize));
+                if maybe_lifetime.is_some() {
+                    abort!(span_range, "AnyRange should not have a lifetime.")
+                };
+                parse_quote! {
+                    #generic : std::ops::RangeBounds<#sub_type>
+                }
+            }
+            // The item is bounded by associated_where_predicates.
+            Special::AnyIterBorrow => {
+                if maybe_sub_type.is_none() {
+                    abort!(span_range,"AnyIterBorrow expects a generic parameter, for example, AnyIterBorrow<usize>.")
+                };
+                if maybe_lifetime.is_some() {
+                    abort!(span_range, "AnyIterBorrow should not have a lifetime.")
+                };
+                parse_quote! {
+                    #generic : IntoIterator
+                }
+            }
+            // Each pass clones the iterator. The item and the iterator are bounded by associated_where_predicates.
+            Special::AnyIterMulti => {
+                if maybe_sub_type.is_none() {
+                    abort!(
+                        span_range,
+                        "AnyIterMulti expects a generic parameter, for example, AnyIterMulti<f64>."
+                    )
+                };
+                if maybe_lifetime.is_some() {
+                    abort!(span_range, "AnyIterMulti should not have a lifetime.")
+                };
+                parse_quote! {
+                    #generic : IntoIterator
+                }
+            }
+            // The item is bounded by associated_where_predicates.
+            Special::AnyTryIter => {
+                if maybe_sub_type.is_none() {
+                    abort!(span_range,"AnyTryIter expects an item type and an error type, for example, AnyTryIter<usize, std::io::Error>.")
+                };
+                if maybe_lifetime.is_some() {
+                    abort!(span_range, "AnyTryIter should not have a lifetime.")
+                };
+                parse_quote! {
+                    #generic : IntoIterator
+                }
+            }
+            Special::AnyOneOrMany => {
+                let sub_type = match maybe_sub_type {
+                    Some(sub_type) => sub_type,
+                    None => {
+                        abort!(span_range,"AnyOneOrMany expects a generic parameter, for example, AnyOneOrMany<AnyPath>.")
+                    }
+                };
+                if maybe_lifetime.is_some() {
+                    abort!(span_range, "AnyOneOrMany should not have a lifetime.")
+                };
+                parse_quote! {
+                    #generic : anyinput::OneOrMany<#sub_type>
+                }
+            }
+            Special::AnyDisplay => {
+                if maybe_sub_type.is_some() {
+                    abort!(span_range,"AnyDisplay should not have a generic parameter, so 'AnyDisplay', not 'AnyDisplay<_>'.")
+                };
+                if maybe_lifetime.is_some() {
+                    abort!(span_range, "AnyDisplay should not have a lifetime.")
+                };
+                parse_quote! {
+                    #generic : std::fmt::Display
+                }
+            }
+            Special::AnyDuration => {
+                if maybe_sub_type.is_some() {
+                    abort!(span_range,"AnyDuration should not have a generic parameter, so 'AnyDuration', not 'AnyDuration<_>'.")
+                };
+                if maybe_lifetime.is_some() {
+                    abort!(span_range, "AnyDuration should not have a lifetime.")
+                };
+                parse_quote! {
+                    #generic : anyinput::ToDuration
+                }
+            }
+            Special::AnyArc => {
+                let sub_type = match maybe_sub_type {
+                    Some(sub_type) => sub_type,
+                    None => {
+                        abort!(span_range,"AnyArc expects a generic parameter, for example, AnyArc<str>, AnyArc<Path>, or AnyArc<[T]>.")
+                    }
+                };
+                if maybe_lifetime.is_some() {
+                    abort!(span_range, "AnyArc should not have a lifetime.")
+                };
+                parse_quote! {
+                    #generic : Into<std::sync::Arc<#sub_type>>
+                }
+            }
+            Special::AnyError => {
+                if maybe_sub_type.is_some() {
+                    abort!(span_range,"AnyError should not have a generic parameter, so 'AnyError', not 'AnyError<_>'.")
+                };
+                if maybe_lifetime.is_some() {
+                    abort!(span_range, "AnyError should not have a lifetime.")
+                };
+                parse_quote! {
+                    #generic : Into<Box<dyn std::error::Error + Send + Sync>>
+                }
+            }
+            Special::AnyAnyhow => {
+                if maybe_sub_type.is_some() {
+                    abort!(span_range,"AnyAnyhow should not have a generic parameter, so 'AnyAnyhow', not 'AnyAnyhow<_>'.")
+                };
+                if maybe_lifetime.is_some() {
+                    abort!(span_range, "AnyAnyhow should not have a lifetime.")
+                };
+                parse_quote! {
+                    #generic : anyinput::IntoAnyhow
+                }
+            }
+            Special::AnySocketAddr => {
+                if maybe_sub_type.is_some() {
+                    abort!(span_range,"AnySocketAddr should not have a generic parameter, so 'AnySocketAddr', not 'AnySocketAddr<_>'.")
+                };
+                if maybe_lifetime.is_some() {
+                    abort!(span_range, "AnySocketAddr should not have a lifetime.")
+                };
+                parse_quote! {
+                    #generic : std::net::ToSocketAddrs
+                }
+            }
         }
     }
 
-    fn ident_to_stmt(&self, name: &Ident) -> Stmt {
+    // If the conversion fails with its own error type, that error must convert into the function's error type.
+    // For example, "<AnyTryInto0 as TryInto<u16>>::Error: Into<MyError>".
+    fn maybe_error_where_predicate(
+        &self,
+        generic: &TypePath,
+        maybe_sub_type: Option<&Type>,
+        output: &ReturnType,
+        span_range: &SpanRange,
+    ) -> Option<WherePredicate> {
+        match self {
+            Special::AnyTryInto => {
+                let error_type = match result_error_type(output) {
+                    Some(error_type) => error_type,
+                    None => {
+                        abort!(span_range, "AnyTryInto converts its input with '?', so the function must return a Result with an explicit error type, for example, 'Result<(), Box<dyn std::error::Error>>', not 'anyhow::Result<()>'.")
+                    }
+                };
+                let sub_type =
+                    maybe_sub_type.expect("Internal error: AnyTryInto should have a sub type.");
+                Some(parse_quote! {
+                    <#generic as TryInto<#sub_type>>::Error: Into<#error_type>
+                })
+            }
+            Special::AnyArray
+            | Special::AnyString
+            | Special::AnyPath
+            | Special::AnyIter
+            | Special::AnyNdArray
+            | Special::AnyBytes
+            | Special::AnyOsStr
+            | Special::AnyCStr
+            | Special::AnyArrayMut
+            | Special::AnyStringMut
+            | Special::AnyNdArrayMut
+            | Special::AnyCow
+            | Special::AnyInto
+            | Special::AnyOption
+            | Special::AnyMap
+            | Special::AnySet
+            | Special::AnyRange
+            | Special::AnyIterBorrow
+            | Special::AnyIterMulti
+            | Special::AnyTryIter
+            | Special::AnyOneOrMany
+            | Special::AnyDisplay
+            | Special::AnyDuration
+            | Special::AnyArc
+            | Special::AnyError
+            | Special::AnyAnyhow
+            | Special::AnySocketAddr => None,
+        }
+    }
+
+    // Some specials bound the item or the iterator of their IntoIterator, for example,
+    // "<AnyIterBorrow0 as IntoIterator>::Item: std::borrow::Borrow<usize>". (These are projections,
+    // not "IntoIterator<Item: ..>", which would need a newer Rust.)
+    fn associated_where_predicates(
+        &self,
+        generic: &TypePath,
+        maybe_sub_type: Option<&Type>,
+    ) -> Vec<WherePredicate> {
+        match self {
+            Special::AnyIterBorrow => {
+                let sub_type =
+                    maybe_sub_type.expect("Internal error: AnyIterBorrow should have a sub type.");
+                vec![parse_quote! {
+                    <#generic as IntoIterator>::Item: std::borrow::Borrow<#sub_type>
+                }]
+            }
+            Special::AnyIterMulti => {
+                let sub_type =
+                    maybe_sub_type.expect("Internal error: AnyIterMulti should have a sub type.");
+                vec![
+                    parse_quote! {
+                        <#generic as IntoIterator>::Item: std::borrow::Borrow<#sub_type>
+                    },
+                    parse_quote! {
+                        <#generic as IntoIterator>::IntoIter: Clone
+                    },
+                ]
+            }
+            // The sub type is the tuple "(T, E)". (See create_pair_sub_type_and_bounds.)
+            Special::AnyTryIter => {
+                let (ok_type, error_type) = split_pair(
+                    maybe_sub_type.expect("Internal error: AnyTryIter should have a sub type."),
+                );
+                vec![parse_quote! {
+                    <#generic as IntoIterator>::Item: anyinput::TryItem<#ok_type, #error_type>
+                }]
+            }
+            Special::AnyArray
+            | Special::AnyString
+            | Special::AnyPath
+            | Special::AnyIter
+            | Special::AnyNdArray
+            | Special::AnyBytes
+            | Special::AnyOsStr
+            | Special::AnyCStr
+            | Special::AnyArrayMut
+            | Special::AnyStringMut
+            | Special::AnyNdArrayMut
+            | Special::AnyCow
+            | Special::AnyInto
+            | Special::AnyTryInto
+            | Special::AnyOption
+            | Special::AnyMap
+            | Special::AnySet
+            | Special::AnyRange
+            | Special::AnyOneOrMany
+            | Special::AnyDisplay
+            | Special::AnyDuration
+            | Special::AnyArc
+            | Special::AnyError
+            | Special::AnyAnyhow
+            | Special::AnySocketAddr => vec![],
+        }
+    }
+
+    // If the special converts to an iterator, bound that iterator, too.
+    // For example, "<AnyIter0 as IntoIterator>::IntoIter: ExactSizeIterator + Send + Sync".
+    fn maybe_into_iter_where_predicate(
+        &self,
+        generic: &TypePath,
+        bounds: &Bounds,
+    ) -> Option<WherePredicate> {
+        if self.converts_to_iterator() && !bounds.is_empty() {
+            Some(parse_quote! {
+                <#generic as IntoIterator>::IntoIter: #bounds
+            })
+        } else {
+            None
+        }
+    }
+
+    // Create the statement that converts the input to a concrete type.
+    // A fallible conversion uses '?', so the function must return a Result. Only a missing return type is
+    // reported here; any other return type, which may be an alias of Result, is left for the compiler to check.
+    fn ident_to_stmt(
+        &self,
+        name: &Ident,
+        maybe_sub_type: Option<&Type>,
+        options: &Options,
+        output: &ReturnType,
+        span_range: &SpanRange,
+    ) -> Stmt {
+        if self.is_fallible() && *output == ReturnType::Default {
+            abort!(
+                span_range,
+                "{} converts its input with '?', so the function must return a Result, for example, 'Result<(), Box<dyn std::error::Error>>'.",
+                self
+            )
+        }
         match &self {
-            Special::AnyArray | Special::AnyString | Special::AnyPath => {
+            Special::AnyArray
+            | Special::AnyString
+            | Special::AnyPath
+            | Special::AnyBytes
+            | Special::AnyOsStr => {
                 parse_quote! {
                     let #name = #name.as_ref();
                 }
             }
-            Special::AnyIter => {
+            Special::AnyIter | Special::AnyMap => {
                 parse_quote! {
                     let #name = #name.into_iter();
                 }
             }
-            Special::AnyNdArray => {
+            Special::AnySet => {
+                parse_quote! {
+                    let #name = #name.into_set();
+                }
+            }
+            Special::AnyOneOrMany => {
+                parse_quote! {
+                    let #name = #name.into_many();
+                }
+            }
+            // The new binding borrows the input, which the shadowing keeps alive.
+            Special::AnyDisplay => {
+                parse_quote! {
+                    let #name = anyinput::display_to_cow(&#name);
+                }
+            }
+            // As with AnyIterBorrow, the sub type is given.
+            Special::AnyIterMulti => {
+                let sub_type =
+                    maybe_sub_type.expect("Internal error: AnyIterMulti should have a sub type.");
+                parse_quote! {
+                    let #name = anyinput::MultiIter::<_, #sub_type>::new(#name);
+                }
+            }
+            // The sub type is given because the iterator's items may borrow as more than one type.
+            Special::AnyIterBorrow => {
+                let sub_type =
+                    maybe_sub_type.expect("Internal error: AnyIterBorrow should have a sub type.");
+                parse_quote! {
+                    let #name = anyinput::BorrowIter::<_, #sub_type>::new(#name);
+                }
+            }
+            // The types are given because an item may convert to more than one kind of Result.
+            Special::AnyTryIter => {
+                let (ok_type, error_type) = split_pair(
+                    maybe_sub_type.expect("Internal error: AnyTryIter should have a sub type."),
+                );
+                parse_quote! {
+                    let #name = anyinput::TryIter::<_, #ok_type, #error_type>::new(#name);
+                }
+            }
+            Special::AnyRange => {
+                let len = match &options.len {
+                    Some(len) => len,
+                    None => {
+                        abort!(span_range, "AnyRange needs a length to resolve against, for example, #[anyinput(len = data.len())].")
+                    }
+                };
+                parse_quote! {
+                    let #name = anyinput::resolve_range(#name, #len)?;
+                }
+            }
+            Special::AnyNdArray
+            | Special::AnyCow
+            | Special::AnyInto
+            | Special::AnyOption
+            | Special::AnyArc
+            | Special::AnyError => {
                 parse_quote! {
                     let #name = #name.into();
                 }
             }
+            // The error converts explicitly because '?' alone can't infer the intermediate type.
+            Special::AnyTryInto => {
+                let error_type = result_error_type(output)
+                    .expect("Internal error: AnyTryInto should have an error type.");
+                parse_quote! {
+                    let #name = #name.try_into().map_err(Into::<#error_type>::into)?;
+                }
+            }
+            // Mutating methods, for example, "mapv_inplace", take the view itself by '&mut'.
+            Special::AnyNdArrayMut => {
+                parse_quote! {
+                    let mut #name = #name.into();
+                }
+            }
+            // The input's binding is made 'mut' (see needs_mut_binding), so it can be borrowed mutably.
+            Special::AnyArrayMut | Special::AnyStringMut => {
+                parse_quote! {
+                    let #name = #name.as_mut();
+                }
+            }
+            Special::AnyCStr => {
+                parse_quote! {
+                    let #name = #name.to_c_str()?;
+                }
+            }
+            Special::AnyDuration => {
+                parse_quote! {
+                    let #name = #name.to_duration()?;
+                }
+            }
+            Special::AnyAnyhow => {
+                parse_quote! {
+                    let #name = #name.into_anyhow();
+                }
+            }
+            // Resolving, for example, "localhost:8080", may find more than one address.
+            Special::AnySocketAddr => {
+                parse_quote! {
+                    let #name: Vec<std::net::SocketAddr> = #name.to_socket_addrs()?.collect();
+                }
+            }
+        }
+    }
+
+    // Tells if the conversion to a concrete type can fail.
+    fn is_fallible(&self) -> bool {
+        match self {
+            Special::AnyCStr
+            | Special::AnyTryInto
+            | Special::AnyRange
+            | Special::AnyDuration
+            | Special::AnySocketAddr => true,
+            Special::AnyArray
+            | Special::AnyString
+            | Special::AnyPath
+            | Special::AnyIter
+            | Special::AnyNdArray
+            | Special::AnyBytes
+            | Special::AnyOsStr
+            | Special::AnyArrayMut
+            | Special::AnyStringMut
+            | Special::AnyNdArrayMut
+            | Special::AnyCow
+            | Special::AnyInto
+            | Special::AnyOption
+            | Special::AnyMap
+            | Special::AnySet
+            | Special::AnyIterBorrow
+            | Special::AnyIterMulti
+            | Special::AnyTryIter
+            | Special::AnyOneOrMany
+            | Special::AnyDisplay
+            | Special::AnyArc
+            | Special::AnyError
+            | Special::AnyAnyhow => false,
+        }
+    }
+
+    // Tells if the conversion uses other inputs, for example, AnyRange's "len = data.len()".
+    // If so, it must come after the other inputs are converted.
+    fn converts_after_others(&self) -> bool {
+        match self {
+            Special::AnyRange => true,
+            Special::AnyArray
+            | Special::AnyString
+            | Special::AnyPath
+            | Special::AnyIter
+            | Special::AnyNdArray
+            | Special::AnyBytes
+            | Special::AnyOsStr
+            | Special::AnyCStr
+            | Special::AnyArrayMut
+            | Special::AnyStringMut
+            | Special::AnyNdArrayMut
+            | Special::AnyCow
+            | Special::AnyInto
+            | Special::AnyTryInto
+            | Special::AnyOption
+            | Special::AnyMap
+            | Special::AnySet
+            | Special::AnyIterBorrow
+            | Special::AnyIterMulti
+            | Special::AnyTryIter
+            | Special::AnyOneOrMany
+            | Special::AnyDisplay
+            | Special::AnyDuration
+            | Special::AnyArc
+            | Special::AnyError
+            | Special::AnyAnyhow
+            | Special::AnySocketAddr => false,
+        }
+    }
+
+    // Tells if the conversion to a concrete type borrows the input mutably.
+    fn needs_mut_binding(&self) -> bool {
+        match self {
+            Special::AnyArrayMut | Special::AnyStringMut => true,
+            Special::AnyArray
+            | Special::AnyString
+            | Special::AnyPath
+            | Special::AnyIter
+            | Special::AnyNdArray
+            | Special::AnyBytes
+            | Special::AnyOsStr
+            | Special::AnyCStr
+            | Special::AnyNdArrayMut
+            | Special::AnyCow
+            | Special::AnyInto
+            | Special::AnyTryInto
+            | Special::AnyOption
+            | Special::AnyMap
+            | Special::AnySet
+            | Special::AnyRange
+            | Special::AnyIterBorrow
+            | Special::AnyIterMulti
+            | Special::AnyTryIter
+            | Special::AnyOneOrMany
+            | Special::AnyDisplay
+            | Special::AnyDuration
+            | Special::AnyArc
+            | Special::AnyError
+            | Special::AnyAnyhow
+            | Special::AnySocketAddr => false,
+        }
+    }
+
+    // Extra bounds for a special that converts to an iterator go on the iterator, not the generic.
+    fn converts_to_iterator(&self) -> bool {
+        match self {
+            Special::AnyIter
+            | Special::AnyMap
+            | Special::AnyIterBorrow
+            | Special::AnyIterMulti
+            | Special::AnyTryIter => true,
+            Special::AnyArray
+            | Special::AnyString
+            | Special::AnyPath
+            | Special::AnyNdArray
+            | Special::AnyBytes
+            | Special::AnyOsStr
+            | Special::AnyCStr
+            | Special::AnyArrayMut
+            | Special::AnyStringMut
+            | Special::AnyNdArrayMut
+            | Special::AnyCow
+            | Special::AnyInto
+            | Special::AnyTryInto
+            | Special::AnyOption
+            | Special::AnySet
+            | Special::AnyRange
+            | Special::AnyOneOrMany
+            | Special::AnyDisplay
+            | Special::AnyDuration
+            | Special::AnyArc
+            | Special::AnyError
+            | Special::AnyAnyhow
+            | Special::AnySocketAddr => false,
+        }
+    }
+
+    // If a special is named by itself, for example, "AnyIter<usize>" rather than "anyinput::AnyIter<usize>" or
+    // an alias, it may be an imported marker. Return the marker with placeholder types, for example, "AnyIter<()>".
+    fn maybe_marker_type(&self, type_path: &TypePath) -> Option<Type> {
+        if type_path.path.leading_colon.is_some() {
+            return None;
+        }
+        let ident = &first_and_only(type_path.path.segments.iter())?.ident;
+        if *ident != self.to_string() {
+            return None;
+        }
+        Some(if !self.has_sub_type() {
+            parse_quote!(#ident)
+        } else if self.has_pair() {
+            parse_quote!(#ident<(), ()>)
+        } else {
+            parse_quote!(#ident<()>)
+        })
+    }
+
+    // Specials such as AnyString take no generic parameter, so their only argument can be extra bounds.
+    fn has_sub_type(&self) -> bool {
+        match self {
+            Special::AnyArray
+            | Special::AnyIter
+            | Special::AnyNdArray
+            | Special::AnyArrayMut
+            | Special::AnyNdArrayMut
+            | Special::AnyCow
+            | Special::AnyInto
+            | Special::AnyTryInto
+            | Special::AnyOption
+            | Special::AnyMap
+            | Special::AnySet
+            | Special::AnyRange
+            | Special::AnyIterBorrow
+            | Special::AnyIterMulti
+            | Special::AnyTryIter
+            | Special::AnyOneOrMany
+            | Special::AnyArc => true,
+            Special::AnyString
+            | Special::AnyPath
+            | Special::AnyBytes
+            | Special::AnyOsStr
+            | Special::AnyCStr
+            | Special::AnyStringMut
+            | Special::AnyDisplay
+            | Special::AnyDuration
+            | Special::AnyError
+            | Special::AnyAnyhow
+            | Special::AnySocketAddr => false,
+        }
+    }
+
+    // AnyMap (a key and a value) and AnyTryIter (an item and an error) take two types rather than one.
+    fn has_pair(&self) -> bool {
+        match self {
+            Special::AnyMap | Special::AnyTryIter => true,
+            Special::AnyArray
+            | Special::AnyString
+            | Special::AnyPath
+            | Special::AnyIter
+            | Special::AnyNdArray
+            | Special::AnyBytes
+            | Special::AnyOsStr
+            | Special::AnyCStr
+            | Special::AnyArrayMut
+            | Special::AnyStringMut
+            | Special::AnyNdArrayMut
+            | Special::AnyCow
+            | Special::AnyInto
+            | Special::AnyTryInto
+            | Special::AnyOption
+            | Special::AnySet
+            | Special::AnyRange
+            | Special::AnyIterBorrow
+            | Special::AnyIterMulti
+            | Special::AnyOneOrMany
+            | Special::AnyDisplay
+            | Special::AnyDuration
+            | Special::AnyArc
+            | Special::AnyError
+            | Special::AnyAnyhow
+            | Special::AnySocketAddr => false,
         }
     }
 
     fn should_add_lifetime(&self) -> bool {
         match self {
-            Special::AnyArray | Special::AnyString | Special::AnyPath | Special::AnyIter => false,
-            Special::AnyNdArray => true,
+            Special::AnyArray
+            | Special::AnyString
+            | Special::AnyPath
+            | Special::AnyIter
+            | Special::AnyBytes
+            | Special::AnyOsStr
+            | Special::AnyCStr
+            | Special::AnyArrayMut
+            | Special::AnyStringMut
+            | Special::AnyInto
+            | Special::AnyTryInto
+            | Special::AnyOption
+            | Special::AnyMap
+            | Special::AnySet
+            | Special::AnyRange
+            | Special::AnyIterBorrow
+            | Special::AnyIterMulti
+            | Special::AnyTryIter
+            | Special::AnyOneOrMany
+            | Special::AnyDisplay
+            | Special::AnyDuration
+            | Special::AnyArc
+            | Special::AnyError
+            | Special::AnyAnyhow
+            | Special::AnySocketAddr => false,
+            Special::AnyNdArray | Special::AnyNdArrayMut | Special::AnyCow => true,
         }
     }
 
-    fn maybe_new(type_path: &TypePath, span_range: &SpanRange) -> Option<(Special, Option<Type>)> {
-        // A special type path has exactly one segment and a name from the Special enum.
-        if type_path.qself.is_none() {
-            if let Some(segment) = first_and_only(type_path.path.segments.iter()) {
-                if let Ok(special) = Special::from_str(segment.ident.to_string().as_ref()) {
-                    let maybe_sub_type =
-                        Special::create_maybe_sub_type(&segment.arguments, span_range);
-                    return Some((special, maybe_sub_type));
-                }
-            }
+    // A name in "only(..)" or "except(..)" must be a special, for example, "AnyPath".
+    fn from_meta_path(meta: &ParseNestedMeta) -> syn::Result<Special> {
+        Special::from_ident(meta.path.require_ident()?)
+    }
+
+    fn from_ident(ident: &Ident) -> syn::Result<Special> {
+        Special::from_str(ident.to_string().as_ref())
+            .map_err(|_| syn::Error::new(ident.span(), format!("Unknown special '{ident}'.")))
+    }
+
+    fn maybe_new(
+        type_path: &TypePath,
+        options: &Options,
+        span_range: &SpanRange,
+    ) -> Option<(Special, Option<Type>, Bounds)> {
+        let (special, segment) = Special::maybe_from_type_path(type_path, options)?;
+        let (maybe_sub_type, extra_bounds) =
+            special.create_maybe_sub_type_and_bounds(&segment.arguments, span_range);
+        Some((special, maybe_sub_type, extra_bounds))
+    }
+
+    // A special type path names an enabled special (or an alias of one), either by itself
+    // or from the anyinput crate, for example, "AnyIter<T>" or "anyinput::AnyIter<T>".
+    fn maybe_from_type_path<'p>(
+        type_path: &'p TypePath,
+        options: &Options,
+    ) -> Option<(Special, &'p PathSegment)> {
+        if type_path.qself.is_some() {
+            return None;
         }
-        None
+        let segment = last_if_special_path(&type_path.path)?;
+        let special = options.maybe_special(&segment.ident)?;
+        Some((special, segment))
     }
 
-    fn create_maybe_sub_type(args: &PathArguments, span_range: &SpanRange) -> Option<Type> {
+    // The generic arguments are an optional type and optional extra bounds.
+    // For example, "AnyIter<usize, ExactSizeIterator + Clone>" or "AnyString<Clone + Send>".
+    fn create_maybe_sub_type_and_bounds(
+        &self,
+        args: &PathArguments,
+        span_range: &SpanRange,
+    ) -> (Option<Type>, Bounds) {
         match args {
-            PathArguments::None => None,
+            PathArguments::None => (None, Bounds::new()),
+            PathArguments::AngleBracketed(ref args) if self.has_pair() => {
+                Special::create_pair_sub_type_and_bounds(args, span_range)
+            }
             PathArguments::AngleBracketed(ref args) => {
-                let arg = first_and_only(args.args.iter()).unwrap_or_else(|| {
-                    abort!(span_range, "Expected at exactly one generic parameter.")
-                });
-                if let GenericArgument::Type(sub_type2) = arg {
-                    Some(sub_type2.clone())
-                } else {
-                    abort!(span_range, "Expected generic parameter to be a type.")
+                let mut arg_iter = args.args.iter();
+                match (arg_iter.next(), arg_iter.next(), arg_iter.next()) {
+                    // A primitive or special, for example, "AnyString<usize>" or "AnyString<AnyPath>",
+                    // is a (disallowed) type, not bounds.
+                    (Some(arg), None, None) if !self.has_sub_type() && !is_type_arg(arg) => {
+                        (None, Special::create_bounds(arg, span_range))
+                    }
+                    (Some(arg), None, None) => (
+                        Some(Special::create_sub_type(arg, span_range)),
+                        Bounds::new(),
+                    ),
+                    (Some(arg), Some(bounds_arg), None) => (
+                        Some(Special::create_sub_type(arg, span_range)),
+                        Special::create_bounds(bounds_arg, span_range),
+                    ),
+                    _ => abort!(
+                        span_range,
+                        "Expected a generic parameter and, optionally, extra bounds, for example, AnyIter<usize, ExactSizeIterator + Clone>."
+                    ),
                 }
             }
             PathArguments::Parenthesized(_) => {
@@ -301,6 +1359,64 @@ impl Special {
         }
     }
 
+    // The generic arguments are two types and optional extra bounds.
+    // The two types become one tuple type, for example, "AnyMap<AnyString, usize>" gives "(AnyString, usize)".
+    fn create_pair_sub_type_and_bounds(
+        args: &AngleBracketedGenericArguments,
+        span_range: &SpanRange,
+    ) -> (Option<Type>, Bounds) {
+        let mut arg_iter = args.args.iter();
+        match (arg_iter.next(), arg_iter.next(), arg_iter.next(), arg_iter.next()) {
+            (Some(first_arg), Some(second_arg), maybe_bounds_arg, None) => {
+                let first = Special::create_sub_type(first_arg, span_range);
+                let second = Special::create_sub_type(second_arg, span_range);
+                let bounds = match maybe_bounds_arg {
+                    Some(bounds_arg) => Special::create_bounds(bounds_arg, span_range),
+                    None => Bounds::new(),
+                };
+                (Some(parse_quote!((#first, #second))), bounds)
+            }
+            _ => abort!(
+                span_range,
+                "Expected two types and, optionally, extra bounds, for example, AnyMap<AnyString, usize, ExactSizeIterator> or AnyTryIter<usize, std::io::Error>."
+            ),
+        }
+    }
+
+    fn create_sub_type(arg: &GenericArgument, span_range: &SpanRange) -> Type {
+        if let GenericArgument::Type(sub_type2) = arg {
+            sub_type2.clone()
+        } else {
+            abort!(span_range, "Expected generic parameter to be a type.")
+        }
+    }
+
+    // Extra bounds can be a list, for example, "ExactSizeIterator + Clone", a single trait, or a lifetime.
+    fn create_bounds(arg: &GenericArgument, span_range: &SpanRange) -> Bounds {
+        if is_type_arg(arg) {
+            abort!(
+                span_range,
+                "Expected extra bounds, for example, 'Clone' or 'Clone + Send', but found the type '{}'.",
+                quote!(#arg)
+            )
+        }
+        match arg {
+            GenericArgument::Type(Type::TraitObject(type_trait_object))
+                if type_trait_object.dyn_token.is_none() =>
+            {
+                type_trait_object.bounds.clone()
+            }
+            GenericArgument::Type(Type::Path(type_path)) if type_path.qself.is_none() => {
+                parse_quote!(#type_path)
+            }
+            GenericArgument::Lifetime(lifetime) => parse_quote!(#lifetime),
+            _ => abort!(
+                span_range,
+                "Expected extra bounds, for example, 'Clone' or 'Clone + Send'."
+            ),
+        }
+    }
+
     // Utility that turns camel case into snake case.
     // For example, "AnyString" -> "any_string".
     fn to_snake_case(&self) -> String {
@@ -322,15 +1438,29 @@ struct DeltaFnArg {
     generic_params: Vec<GenericParam>,
     where_predicates: Vec<WherePredicate>,
     stmt: Option<Stmt>,
+    stmt_is_late: bool, // if true, the statement must come after other inputs' statements
+    marker_types: Vec<Type>,
 }
 
 impl DeltaFnArg {
     // If a function argument contains a special type(s), re-write it/them.
-    fn new(fn_arg: &FnArg, suffix_iter: &mut impl Iterator<Item = String>) -> DeltaFnArg {
+    fn new(
+        fn_arg: &FnArg,
+        options: &Options,
+        output: &ReturnType,
+        suffix_iter: &mut impl Iterator<Item = String>,
+    ) -> DeltaFnArg {
         // If the function input is normal (not self, not a macro, etc) ...
         if let Some((pat_ident, pat_type)) = DeltaFnArg::is_normal_fn_arg(fn_arg) {
+            // Apply any options given on the input and then remove them.
+            let options = options
+                .with_input_attrs(&pat_type.attrs)
+                .unwrap_or_else(|error| abort!(error.span(), "{}", error));
+            let mut pat_type = pat_type.clone();
+            pat_type.attrs.retain(|attr| !is_anyinput_attr(attr));
+
             // Replace any specials in the type with generics.
-            DeltaFnArg::replace_any_specials(pat_type.clone(), pat_ident, suffix_iter)
+            DeltaFnArg::replace_any_specials(pat_type, pat_ident, options, output, suffix_iter)
         } else {
             // if input is not normal, return it unchanged.
             DeltaFnArg {
@@ -338,6 +1468,8 @@ impl DeltaFnArg {
                 generic_params: vec![],
                 where_predicates: vec![],
                 stmt: None,
+                stmt_is_late: false,
+                marker_types: vec![],
             }
         }
     }
@@ -362,17 +1494,35 @@ impl DeltaFnArg {
     fn replace_any_specials(
         old_pat_type: PatType,
         pat_ident: &PatIdent,
+        options: Options,
+        output: &ReturnType,
         suffix_iter: &mut impl Iterator<Item = String>,
     ) -> DeltaFnArg {
-        let mut delta_pat_type = DeltaPatType::new(suffix_iter);
-        let new_pat_type = delta_pat_type.fold_pat_type(old_pat_type);
+        let mut delta_pat_type = DeltaPatType::new(options, output, suffix_iter);
+        let mut new_pat_type = delta_pat_type.fold_pat_type(old_pat_type);
+
+        // If the conversion borrows the input mutably, for example, "x.as_mut()", make the input's binding 'mut'.
+        if let Some((special, _, _)) = &delta_pat_type.last_special {
+            if special.needs_mut_binding() {
+                if let Pat::Ident(pat_ident) = &mut *new_pat_type.pat {
+                    pat_ident.mutability = Some(parse_quote!(mut));
+                }
+            }
+        }
+
+        let stmt_is_late = match &delta_pat_type.last_special {
+            Some((special, _, _)) => special.converts_after_others(),
+            None => false,
+        };
 
         // Return the new function input, any statements to add, and any new generic definitions.
         DeltaFnArg {
             fn_arg: FnArg::Typed(new_pat_type),
             stmt: delta_pat_type.generate_any_stmt(pat_ident),
+            stmt_is_late,
             generic_params: delta_pat_type.generic_params,
             where_predicates: delta_pat_type.where_predicates,
+            marker_types: delta_pat_type.marker_types,
         }
     }
 }
@@ -381,7 +1531,10 @@ struct DeltaPatType<'a> {
     generic_params: Vec<GenericParam>,
     where_predicates: Vec<WherePredicate>,
     suffix_iter: &'a mut dyn Iterator<Item = String>,
-    last_special: Option<Special>,
+    last_special: Option<(Special, Option<Type>, SpanRange)>,
+    options: Options,
+    output: &'a ReturnType,
+    marker_types: Vec<Type>,
 }
 
 impl Fold for DeltaPatType<'_> {
@@ -389,13 +1542,21 @@ impl Fold for DeltaPatType<'_> {
         let span_range = SpanRange::from_tokens(&type_path_old); // used by abort!
 
         // Apply "fold" recursively to process specials in subtypes, for example, Vec<AnyString>.
-        let type_path_middle = syn::fold::fold_type_path(self, type_path_old);
+        // A special without a type, for example, AnyPath<Send>, has only bounds, so it is left as is.
+        let type_path_middle = match Special::maybe_from_type_path(&type_path_old, &self.options) {
+            Some((special, _)) if !special.has_sub_type() => type_path_old,
+            _ => syn::fold::fold_type_path(self, type_path_old),
+        };
 
         // If this type is special, replace it with a generic.
-        if let Some((special, maybe_sub_types)) = Special::maybe_new(&type_path_middle, &span_range)
+        if let Some((special, maybe_sub_types, extra_bounds)) =
+            Special::maybe_new(&type_path_middle, &self.options, &span_range)
         {
-            self.last_special = Some(special.clone()); // remember the special found (used for stmt generation)
-            self.create_and_define_generic(special, maybe_sub_types, &span_range)
+            self.last_special = Some((special.clone(), maybe_sub_types.clone(), span_range)); // remember the special found (used for stmt generation)
+            if let Some(marker_type) = special.maybe_marker_type(&type_path_middle) {
+                self.marker_types.push(marker_type);
+            }
+            self.create_and_define_generic(special, maybe_sub_types, extra_bounds, &span_range)
         } else {
             self.last_special = None;
             type_path_middle
@@ -404,12 +1565,19 @@ impl Fold for DeltaPatType<'_> {
 }
 
 impl<'a> DeltaPatType<'a> {
-    fn new(suffix_iter: &'a mut dyn Iterator<Item = String>) -> Self {
+    fn new(
+        options: Options,
+        output: &'a ReturnType,
+        suffix_iter: &'a mut dyn Iterator<Item = String>,
+    ) -> Self {
         DeltaPatType {
             generic_params: vec![],
             where_predicates: vec![],
             suffix_iter,
             last_special: None,
+            options,
+            output,
+            marker_types: vec![],
         }
     }
 
@@ -417,8 +1585,14 @@ impl<'a> DeltaPatType<'a> {
     // from its generic type to to a concrete type.
     // For example,  "let x = x.into_iter();" for AnyIter.
     fn generate_any_stmt(&self, pat_ident: &PatIdent) -> Option<Stmt> {
-        if let Some(special) = &self.last_special {
-            let stmt = special.ident_to_stmt(&pat_ident.ident);
+        if let Some((special, maybe_sub_type, span_range)) = &self.last_special {
+            let stmt = special.ident_to_stmt(
+                &pat_ident.ident,
+                maybe_sub_type.as_ref(),
+                &self.options,
+                self.output,
+                span_range,
+            );
             Some(stmt)
         } else {
             None
@@ -426,23 +1600,54 @@ impl<'a> DeltaPatType<'a> {
     }
 
     // Define the generic type, for example, "AnyString3: AsRef<str>", and remember the definition.
+    // Any extra bounds, for example, "Clone", and those from the options, for example, "Send + Sync",
+    // are added, too.
     fn create_and_define_generic(
         &mut self,
         special: Special,
         maybe_sub_type: Option<Type>,
+        extra_bounds: Bounds,
         span_range: &SpanRange,
     ) -> TypePath {
         let generic = self.create_generic(&special); // for example, "AnyString3"
         let maybe_lifetime = self.create_maybe_lifetime(&special);
         let where_predicate = special.special_to_where_predicate(
             &generic,
-            maybe_sub_type,
+            maybe_sub_type.clone(),
             maybe_lifetime,
             span_range,
         );
+        let associated_where_predicates =
+            special.associated_where_predicates(&generic, maybe_sub_type.as_ref());
+        let maybe_error_where_predicate = special.maybe_error_where_predicate(
+            &generic,
+            maybe_sub_type.as_ref(),
+            self.output,
+            span_range,
+        );
+        // An AnyIter's extra bounds go on its iterator. The options' bounds go everywhere.
+        let option_bounds = self.options.extra_bounds();
+        let (generic_bounds, into_iter_bounds) = if special.converts_to_iterator() {
+            (
+                option_bounds.clone(),
+                concat_bounds(extra_bounds, &option_bounds),
+            )
+        } else {
+            (concat_bounds(extra_bounds, &option_bounds), option_bounds)
+        };
         let generic_param: GenericParam = parse_quote!(#generic);
         self.generic_params.push(generic_param);
-        self.where_predicates.push(where_predicate);
+        self.where_predicates
+            .push(append_bounds(where_predicate, &generic_bounds));
+        self.where_predicates.extend(associated_where_predicates);
+        if let Some(where_predicate) =
+            special.maybe_into_iter_where_predicate(&generic, &into_iter_bounds)
+        {
+            self.where_predicates.push(where_predicate);
+        }
+        if let Some(where_predicate) = maybe_error_where_predicate {
+            self.where_predicates.push(where_predicate);
+        }
         generic
     }
 
@@ -480,6 +1685,115 @@ impl<'a> DeltaPatType<'a> {
     }
 }
 
+// Utility that adds bounds to a where predicate.
+// For example, "AnyString0: AsRef<str>" -> "AnyString0: AsRef<str> + Send + Sync".
+fn append_bounds(mut where_predicate: WherePredicate, bounds: &Bounds) -> WherePredicate {
+    if let WherePredicate::Type(predicate_type) = &mut where_predicate {
+        predicate_type.bounds.extend(bounds.iter().cloned());
+    }
+    where_predicate
+}
+
+// Utility that returns the last segment of a path that is either one segment or
+// "anyinput::" (or "::anyinput::") followed by one segment.
+fn last_if_special_path(path: &Path) -> Option<&PathSegment> {
+    if path.leading_colon.is_none() {
+        if let Some(segment) = first_and_only(path.segments.iter()) {
+            return Some(segment);
+        }
+    }
+    let mut segment_iter = path.segments.iter();
+    let first = segment_iter.next()?;
+    if first.ident == "anyinput" && first.arguments.is_none() {
+        first_and_only(segment_iter)
+    } else {
+        None
+    }
+}
+
+// Utility that tells if a generic argument is surely a type, not a trait, because it names a
+// primitive, a common standard type, or a special, for example, "usize", "String", or "AnyPath".
+fn is_type_arg(arg: &GenericArgument) -> bool {
+    const PRIMITIVES: [&str; 17] = [
+        "bool", "char", "str", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32",
+        "i64", "i128", "isize", "f32", "f64",
+    ];
+    const STD_TYPES: [&str; 18] = [
+        "String",
+        "PathBuf",
+        "OsString",
+        "CString",
+        "Vec",
+        "VecDeque",
+        "Box",
+        "Rc",
+        "Arc",
+        "Option",
+        "Result",
+        "Cow",
+        "HashMap",
+        "HashSet",
+        "BTreeMap",
+        "BTreeSet",
+        "Duration",
+        "SocketAddr",
+    ];
+    match arg {
+        GenericArgument::Type(Type::Path(type_path)) if type_path.qself.is_none() => {
+            if let Some(ident) = type_path.path.get_ident() {
+                if PRIMITIVES.iter().any(|primitive| ident == primitive) {
+                    return true;
+                }
+            }
+            if let Some(segment) = type_path.path.segments.last() {
+                if STD_TYPES.iter().any(|std_type| segment.ident == std_type) {
+                    return true;
+                }
+            }
+            last_if_special_path(&type_path.path)
+                .is_some_and(|segment| Special::from_ident(&segment.ident).is_ok())
+        }
+        GenericArgument::Type(Type::TraitObject(_)) => false,
+        // For example, "&str" or "[u8]".
+        GenericArgument::Type(_) => true,
+        _ => false,
+    }
+}
+
+// Utility that finds the error type of a function that returns, for example, "Result<usize, MyError>".
+fn result_error_type(output: &ReturnType) -> Option<&Type> {
+    if let ReturnType::Type(_, return_type) = output {
+        if let Type::Path(type_path) = &**return_type {
+            if let Some(segment) = type_path.path.segments.last() {
+                if segment.ident == "Result" {
+                    if let PathArguments::AngleBracketed(args) = &segment.arguments {
+                        if let (2, Some(GenericArgument::Type(error_type))) =
+                            (args.args.len(), args.args.last())
+                        {
+                            return Some(error_type);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+// Utility that splits a pair sub type, for example, "(usize, std::io::Error)", into its two types.
+fn split_pair(sub_type: &Type) -> (&Type, &Type) {
+    match sub_type {
+        Type::Tuple(tuple) if tuple.elems.len() == 2 => (&tuple.elems[0], &tuple.elems[1]),
+        _ => panic!("Internal error: expected a pair sub type."),
+    }
+}
+
+// Utility that joins two lists of bounds.
+fn concat_bounds(mut bounds: Bounds, more_bounds: &Bounds) -> Bounds {
+    bounds.extend(more_bounds.iter().cloned());
+    bounds
+}
+
 // Utility that tells if an iterator contains exactly one element.
 fn first_and_only<T, I: Iterator<Item = T>>(mut iter: I) -> Option<T> {
     let first = iter.next()?;
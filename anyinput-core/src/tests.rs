@@ -60,6 +60,15 @@ fn one_input() {
     where
         AnyString0: AsRef<str>
     {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyString,)>;
+            }
+        }
         let s = s.as_ref();
         s.len()
     }
@@ -95,6 +104,15 @@ fn two_inputs() -> anyhow::Result<()> {
         AnyString0: AsRef<str>,
         AnyString1: AsRef<str>
     {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyString,)>;
+            }
+        }
         let b = b.as_ref();
         let a = a.as_ref();
         let len = a.len() + b.len();
@@ -157,6 +175,15 @@ fn one_plus_two_input() -> anyhow::Result<()> {
     where
         AnyString0: AsRef<str>
     {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyString,)>;
+            }
+        }
         let s = s.as_ref();
         let len = s.len() + a + b;
         Ok(len)
@@ -196,6 +223,15 @@ fn one_path_input() {
     where
         AnyPath0: AsRef<std::path::Path>
     {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyPath,)>;
+            }
+        }
         let p = p.as_ref();
         let count = p.iter().count();
         Ok(count)
@@ -229,6 +265,15 @@ fn one_iter_usize_input() {
     where
         AnyIter0: IntoIterator<Item = usize>
     {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyIter<()>,)>;
+            }
+        }
         let i = i.into_iter();
         let count = i.count();
         Ok(count)
@@ -262,6 +307,15 @@ fn one_iter_i32() {
     where
         AnyIter0: IntoIterator<Item = i32>
     {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyIter<()>,)>;
+            }
+        }
         let i = i.into_iter();
         let count = i.count();
         Ok(count)
@@ -295,6 +349,15 @@ fn one_iter_t() {
     where
         AnyIter0: IntoIterator<Item = T>
     {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyIter<()>,)>;
+            }
+        }
         let i = i.into_iter();
         let count = i.count();
         Ok(count)
@@ -331,6 +394,15 @@ fn one_iter_t_where() {
         T: Copy,
         AnyIter0: IntoIterator<Item = T>
     {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyIter<()>,)>;
+            }
+        }
         let i = i.into_iter();
         let count = i.count();
         Ok(count)
@@ -364,6 +436,15 @@ fn one_iter_path() {
         AnyPath0: AsRef<std::path::Path>,
         AnyIter1: IntoIterator<Item = AnyPath0>
     {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyPath, AnyIter<()>,)>;
+            }
+        }
         let i = i.into_iter();
         let sum_count = i.map(|x| x.as_ref().iter().count()).sum();
         Ok(sum_count)
@@ -400,6 +481,15 @@ fn one_vec_path() {
         where
             AnyPath0: AsRef<std::path::Path>
         {
+            {
+                #[allow(unused_imports)]
+                use anyinput::*;
+                {
+                    #[allow(unused_imports)]
+                    use self::*;
+                    let _: ::core::marker::PhantomData<(AnyPath,)>;
+                }
+            }
             let sum_count = i.iter().map(|x| x.as_ref().iter().count()).sum();
             Ok(sum_count)
         }
@@ -431,6 +521,15 @@ fn one_array_usize_input() {
     where
         AnyArray0: AsRef<[usize]>
     {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyArray<()>,)>;
+            }
+        }
         let a = a.as_ref();
         let len = a.len();
         Ok(len)
@@ -451,220 +550,1512 @@ fn one_array_usize_input() {
     assert_eq!(any_array_len([1, 2, 3]).unwrap(), 3);
 }
 
-#[cfg(feature = "ndarray")]
-#[test]
-fn understand_lifetime_parse() {
-    let a = Lifetime::new("'a", syn::__private::Span::call_site());
-    println!("a: {}", quote!(#a));
-    let b: Lifetime = parse_quote!('a);
-    println!("b: {}", quote!(#b));
-
-    let _generic_param: GenericParam = parse_quote!(S1: Into<ndarray::ArrayView1<'S0, S2>>);
-    println!("gp: {}", quote!(#_generic_param));
-    println!("done");
-}
-
-#[cfg(feature = "ndarray")]
 #[test]
-fn one_ndarray_usize_input() {
+fn one_bytes_input() {
     let before = quote! {
-    pub fn any_array_len(a: AnyNdArray<usize>) -> Result<usize, anyhow::Error> {
-        let len = a.len();
-        Ok(len)
-    }        };
+    pub fn checksum(bytes: AnyBytes) -> u32 {
+        bytes.iter().map(|&b| b as u32).sum()
+    }
+    };
     let expected = quote! {
-    pub fn any_array_len<'any_nd_array1, AnyNdArray0>(
-        a: AnyNdArray0
-    ) -> Result<usize, anyhow::Error>
+    pub fn checksum<AnyBytes0>(bytes: AnyBytes0) -> u32
     where
-        AnyNdArray0: Into<ndarray::ArrayView1<'any_nd_array1, usize> >
+        AnyBytes0: AsRef<[u8]>
     {
-        let a = a.into();
-        let len = a.len();
-        Ok(len)
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyBytes,)>;
+            }
+        }
+        let bytes = bytes.as_ref();
+        bytes.iter().map(|&b| b as u32).sum()
     }
     };
 
     let after = anyinput_core(quote!(), before);
     assert_tokens_eq(&expected, &after);
 
-    // The lines are long enough that Clippy would like a comma after
-    // a:AnyNdArray0, but the macro doesn't do that because
-    // it doesn't know the line length.
-    pub fn any_array_len<'any_nd_array1, AnyNdArray0>(
-        a: AnyNdArray0,
-    ) -> Result<usize, anyhow::Error>
+    pub fn checksum<AnyBytes0>(bytes: AnyBytes0) -> u32
     where
-        AnyNdArray0: Into<ndarray::ArrayView1<'any_nd_array1, usize>>,
+        AnyBytes0: AsRef<[u8]>,
     {
-        let a = a.into();
-        let len = a.len();
-        Ok(len)
+        let bytes = bytes.as_ref();
+        bytes.iter().map(|&b| b as u32).sum()
     }
-    assert_eq!(any_array_len([1, 2, 3].as_ref()).unwrap(), 3);
+    assert_eq!(checksum("ab"), 97 + 98);
+    assert_eq!(checksum(vec![1u8, 2, 3]), 6);
 }
 
 #[test]
-#[cfg(feature = "ndarray")]
-fn complex() {
+fn one_iter_os_str() {
     let before = quote! {
-        pub fn complex_total(
-            a: usize,
-            b: AnyIter<Vec<AnyArray<AnyPath>>>,
-            c: AnyNdArray<usize>
-        ) -> Result<usize, anyhow::Error> {
-            let mut total = a + c.sum();
-            for vec in b {
-                for any_array in vec {
-                    let any_array = any_array.as_ref();
-                    for any_path in any_array.iter() {
-                        let any_path = any_path.as_ref();
-                        total += any_path.iter().count();
-                    }
-                }
-            }
-            Ok(total)
-            }
+    pub fn arg_count(args: AnyIter<AnyOsStr>, program: AnyOsStr) -> usize {
+        let mut command = std::process::Command::new(program);
+        command.args(args);
+        command.get_args().count()
+    }
     };
     let expected = quote! {
-    pub fn complex_total<'any_nd_array4, AnyPath0, AnyArray1, AnyIter2, AnyNdArray3>(
-        a: usize,
-        b: AnyIter2,
-        c: AnyNdArray3
-    ) -> Result<usize, anyhow::Error>
+    pub fn arg_count<AnyOsStr0, AnyIter1, AnyOsStr2>(args: AnyIter1, program: AnyOsStr2) -> usize
     where
-        AnyPath0: AsRef<std::path::Path>,
-        AnyArray1: AsRef<[AnyPath0]>,
-        AnyIter2: IntoIterator<Item = Vec<AnyArray1> >,
-        AnyNdArray3: Into<ndarray::ArrayView1<'any_nd_array4, usize> >
+        AnyOsStr0: AsRef<std::ffi::OsStr>,
+        AnyIter1: IntoIterator<Item = AnyOsStr0>,
+        AnyOsStr2: AsRef<std::ffi::OsStr>
     {
-        let c = c.into();
-        let b = b.into_iter();
-        let mut total = a + c.sum();
-        for vec in b {
-            for any_array in vec {
-                let any_array = any_array.as_ref();
-                for any_path in any_array.iter() {
-                    let any_path = any_path.as_ref();
-                    total += any_path.iter().count();
-                }
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyOsStr, AnyIter<()>,)>;
             }
         }
-        Ok(total)
+        let program = program.as_ref();
+        let args = args.into_iter();
+        let mut command = std::process::Command::new(program);
+        command.args(args);
+        command.get_args().count()
     }
     };
 
     let after = anyinput_core(quote!(), before);
     assert_tokens_eq(&expected, &after);
 
-    pub fn complex_total<'any_nd_array4, AnyPath0, AnyArray1, AnyIter2, AnyNdArray3>(
-        a: usize,
-        b: AnyIter2,
-        c: AnyNdArray3,
-    ) -> Result<usize, anyhow::Error>
+    pub fn arg_count<AnyOsStr0, AnyIter1, AnyOsStr2>(args: AnyIter1, program: AnyOsStr2) -> usize
     where
-        AnyPath0: AsRef<std::path::Path>,
-        AnyArray1: AsRef<[AnyPath0]>,
-        AnyIter2: IntoIterator<Item = Vec<AnyArray1>>,
-        AnyNdArray3: Into<ndarray::ArrayView1<'any_nd_array4, usize>>,
+        AnyOsStr0: AsRef<std::ffi::OsStr>,
+        AnyIter1: IntoIterator<Item = AnyOsStr0>,
+        AnyOsStr2: AsRef<std::ffi::OsStr>,
     {
-        let c = c.into();
-        let b = b.into_iter();
-        let mut total = a + c.sum();
-        for vec in b {
-            for any_array in vec {
-                let any_array = any_array.as_ref();
-                for any_path in any_array.iter() {
-                    let any_path = any_path.as_ref();
-                    total += any_path.iter().count();
-                }
+        let program = program.as_ref();
+        let args = args.into_iter();
+        let mut command = std::process::Command::new(program);
+        command.args(args);
+        command.get_args().count()
+    }
+    assert_eq!(arg_count(["-l", "-a"], "ls"), 2);
+}
+
+#[test]
+fn one_c_str_input() {
+    let before = quote! {
+    pub fn c_len(s: AnyCStr, names: AnyIter<AnyCStr>) -> Result<usize, std::ffi::NulError> {
+        let mut len = s.to_bytes().len();
+        for name in names {
+            len += name.to_c_str()?.to_bytes().len();
+        }
+        Ok(len)
+    }
+    };
+    let expected = quote! {
+    pub fn c_len<AnyCStr0, AnyCStr1, AnyIter2>(s: AnyCStr0, names: AnyIter2) -> Result<usize, std::ffi::NulError>
+    where
+        AnyCStr0: anyinput::ToCStr,
+        AnyCStr1: anyinput::ToCStr,
+        AnyIter2: IntoIterator<Item = AnyCStr1>
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyCStr, AnyIter<()>,)>;
             }
         }
-        Ok(total)
+        let names = names.into_iter();
+        let s = s.to_c_str()?;
+        let mut len = s.to_bytes().len();
+        for name in names {
+            len += name.to_c_str()?.to_bytes().len();
+        }
+        Ok(len)
     }
+    };
 
-    assert_eq!(
-        complex_total(17, [vec![["one"]]], [1, 2, 3].as_ref()).unwrap(),
-        24
-    );
+    let after = anyinput_core(quote!(), before);
+    assert_tokens_eq(&expected, &after);
 }
 
 #[test]
-fn doc_write() -> Result<(), anyhow::Error> {
+fn mut_inputs() {
     let before = quote! {
-    fn len_plus_2(s: AnyString) -> Result<usize, anyhow::Error> {
-        Ok(s.len()+2)
-    }        };
-    let after = anyinput_core(quote!(), before);
-    println!("after: {}", quote! { #after});
+    pub fn fill_and_shout(array: AnyArrayMut<usize>, s: AnyStringMut, value: usize) {
+        array.fill(value);
+        s.make_ascii_uppercase();
+    }
+    };
     let expected = quote! {
-    fn len_plus_2<AnyString0>(s: AnyString0) -> Result<usize, anyhow::Error>
+    pub fn fill_and_shout<AnyArrayMut0, AnyStringMut1>(mut array: AnyArrayMut0, mut s: AnyStringMut1, value: usize)
     where
-        AnyString0: AsRef<str>
+        AnyArrayMut0: AsMut<[usize]>,
+        AnyStringMut1: AsMut<str>
     {
-        let s = s.as_ref();
-        Ok(s.len() + 2)
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyArrayMut<()>, AnyStringMut,)>;
+            }
+        }
+        let s = s.as_mut();
+        let array = array.as_mut();
+        array.fill(value);
+        s.make_ascii_uppercase();
     }
     };
+
+    let after = anyinput_core(quote!(), before);
     assert_tokens_eq(&expected, &after);
 
-    fn len_plus_2<AnyString0>(s: AnyString0) -> Result<usize, anyhow::Error>
-    where
-        AnyString0: AsRef<str>,
+    pub fn fill_and_shout<AnyArrayMut0, AnyStringMut1>(
+        mut array: AnyArrayMut0,
+        mut s: AnyStringMut1,
+        value: usize,
+    ) where
+        AnyArrayMut0: AsMut<[usize]>,
+        AnyStringMut1: AsMut<str>,
     {
-        let s = s.as_ref();
-        Ok(s.len() + 2)
+        let s = s.as_mut();
+        let array = array.as_mut();
+        array.fill(value);
+        s.make_ascii_uppercase();
     }
-    assert_eq!(len_plus_2("hello")?, 7);
-
-    Ok(())
+    let mut vec = vec![1, 2, 3];
+    let mut string = "hello".to_string();
+    fill_and_shout(&mut vec, &mut string, 0);
+    assert_eq!(vec, [0, 0, 0]);
+    assert_eq!(string, "HELLO");
 }
 
 #[test]
-#[should_panic(
-    expected = "proc-macro-error API cannot be used outside of `entry_point` invocation, perhaps you forgot to annotate your #[proc_macro] function with `#[proc_macro_error]"
-)]
-fn one_bad_input_1() {
+fn cow_inputs() {
     let before = quote! {
-    pub fn any_str_len(s: AnyIter<AnyString,usize>) -> Result<usize, anyhow::Error> {
-        let len = s.len();
-        Ok(len)
+    pub fn lengths(s: AnyCow<str>, array: AnyCow<[usize], Send>) -> (Cow<'static, str>, usize) {
+        (Cow::Owned(s.into_owned()), array.len())
     }
-       };
-    let _after = anyinput_core(quote!(), before);
+    };
+    let expected = quote! {
+    pub fn lengths<'any_cow1, 'any_cow3, AnyCow0, AnyCow2>(s: AnyCow0, array: AnyCow2) -> (Cow<'static, str>, usize)
+    where
+        AnyCow0: Into<std::borrow::Cow<'any_cow1, str> >,
+        AnyCow2: Into<std::borrow::Cow<'any_cow3, [usize]> > + Send
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyCow<()>,)>;
+            }
+        }
+        let array = array.into();
+        let s = s.into();
+        (Cow::Owned(s.into_owned()), array.len())
+    }
+    };
+
+    let after = anyinput_core(quote!(), before);
+    assert_tokens_eq(&expected, &after);
 }
 
 #[test]
-#[should_panic(
-    expected = "proc-macro-error API cannot be used outside of `entry_point` invocation, perhaps you forgot to annotate your #[proc_macro] function with `#[proc_macro_error]"
-)]
-fn one_bad_input_2() {
+fn into_and_try_into_inputs() {
     let before = quote! {
-    pub fn any_str_len(s: AnyIter<3>) -> Result<usize, anyhow::Error> {
-        let len = s.len();
-        Ok(len)
+    pub fn scaled_port(scale: AnyInto<u64>, port: AnyTryInto<u16>) -> Result<u64, Box<dyn std::error::Error> > {
+        Ok(scale * port as u64)
     }
-       };
-    let _after = anyinput_core(quote!(), before);
-}
+    };
+    let expected = quote! {
+    pub fn scaled_port<AnyInto0, AnyTryInto1>(scale: AnyInto0, port: AnyTryInto1) -> Result<u64, Box<dyn std::error::Error> >
+    where
+        AnyInto0: Into<u64>,
+        AnyTryInto1: TryInto<u16>,
+        <AnyTryInto1 as TryInto<u16> >::Error: Into<Box<dyn std::error::Error> >
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyInto<()>, AnyTryInto<()>,)>;
+            }
+        }
+        let port = port.try_into().map_err(Into::<Box<dyn std::error::Error> >::into)?;
+        let scale = scale.into();
+        Ok(scale * port as u64)
+    }
+    };
 
-#[test]
-#[should_panic(
-    expected = "proc-macro-error API cannot be used outside of `entry_point` invocation, perhaps you forgot to annotate your #[proc_macro] function with `#[proc_macro_error]"
-)]
-fn one_bad_input_3() {
-    let before = quote! {
-    pub fn any_str_len(s: AnyIter(AnyString)) {
-        s.len()
+    let after = anyinput_core(quote!(), before);
+    assert_tokens_eq(&expected, &after);
+
+    pub fn scaled_port<AnyInto0, AnyTryInto1>(
+        scale: AnyInto0,
+        port: AnyTryInto1,
+    ) -> Result<u64, Box<dyn std::error::Error>>
+    where
+        AnyInto0: Into<u64>,
+        AnyTryInto1: TryInto<u16>,
+        <AnyTryInto1 as TryInto<u16>>::Error: Into<Box<dyn std::error::Error>>,
+    {
+        let port = port
+            .try_into()
+            .map_err(Into::<Box<dyn std::error::Error>>::into)?;
+        let scale = scale.into();
+        Ok(scale * port as u64)
     }
-       };
-    let _after = anyinput_core(quote!(), before);
+    assert_eq!(scaled_port(2u8, 80u32).unwrap(), 160);
+    assert!(scaled_port(2u8, 70_000u32).is_err());
 }
 
 #[test]
-#[should_panic(
-    expected = "proc-macro-error API cannot be used outside of `entry_point` invocation, perhaps you forgot to annotate your #[proc_macro] function with `#[proc_macro_error]"
-)]
+fn option_inputs() {
+    let before = quote! {
+    pub fn row_count(max_rows: AnyOption<usize>, name: AnyOption<AnyString>) -> usize {
+        max_rows.unwrap_or(100) + name.map_or(0, |name| name.as_ref().len())
+    }
+    };
+    let expected = quote! {
+    pub fn row_count<AnyOption0, AnyString1, AnyOption2>(max_rows: AnyOption0, name: AnyOption2) -> usize
+    where
+        AnyOption0: Into<Option<usize> >,
+        AnyString1: AsRef<str>,
+        AnyOption2: Into<Option<AnyString1> >
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyOption<()>, AnyString,)>;
+            }
+        }
+        let name = name.into();
+        let max_rows = max_rows.into();
+        max_rows.unwrap_or(100) + name.map_or(0, |name| name.as_ref().len())
+    }
+    };
+
+    let after = anyinput_core(quote!(), before);
+    assert_tokens_eq(&expected, &after);
+
+    pub fn row_count<AnyOption0, AnyString1, AnyOption2>(
+        max_rows: AnyOption0,
+        name: AnyOption2,
+    ) -> usize
+    where
+        AnyOption0: Into<Option<usize>>,
+        AnyString1: AsRef<str>,
+        AnyOption2: Into<Option<AnyString1>>,
+    {
+        let name = name.into();
+        let max_rows = max_rows.into();
+        max_rows.unwrap_or(100) + name.map_or(0, |name| name.as_ref().len())
+    }
+    assert_eq!(row_count(5, "abc"), 8);
+    assert_eq!(row_count::<_, String, _>(None, None), 100);
+}
+
+#[test]
+fn map_inputs() {
+    let before = quote! {
+    pub fn total_len(headers: AnyMap<AnyString, AnyPath, ExactSizeIterator>) -> usize {
+        headers.len()
+    }
+    };
+    let expected = quote! {
+    pub fn total_len<AnyString0, AnyPath1, AnyMap2>(headers: AnyMap2) -> usize
+    where
+        AnyString0: AsRef<str>,
+        AnyPath1: AsRef<std::path::Path>,
+        AnyMap2: IntoIterator<Item = (AnyString0, AnyPath1)>,
+        <AnyMap2 as IntoIterator>::IntoIter: ExactSizeIterator
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyString, AnyPath, AnyMap<(), ()>,)>;
+            }
+        }
+        let headers = headers.into_iter();
+        headers.len()
+    }
+    };
+
+    let after = anyinput_core(quote!(), before);
+    assert_tokens_eq(&expected, &after);
+
+    pub fn total_len<AnyString0, AnyPath1, AnyMap2>(headers: AnyMap2) -> usize
+    where
+        AnyString0: AsRef<str>,
+        AnyPath1: AsRef<std::path::Path>,
+        AnyMap2: IntoIterator<Item = (AnyString0, AnyPath1)>,
+        <AnyMap2 as IntoIterator>::IntoIter: ExactSizeIterator,
+    {
+        let headers = headers.into_iter();
+        headers.len()
+    }
+    assert_eq!(total_len([("a", "b"), ("c", "d")]), 2);
+}
+
+#[test]
+fn set_input() {
+    let before = quote! {
+    pub fn keep(ids: AnySet<u32>, values: AnyArray<u32>) -> Vec<u32> {
+        values.iter().copied().filter(|id| ids.contains(id)).collect()
+    }
+    };
+    let expected = quote! {
+    pub fn keep<AnySet0, AnyArray1>(ids: AnySet0, values: AnyArray1) -> Vec<u32>
+    where
+        AnySet0: anyinput::IntoSet<u32>,
+        AnyArray1: AsRef<[u32]>
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnySet<()>, AnyArray<()>,)>;
+            }
+        }
+        let values = values.as_ref();
+        let ids = ids.into_set();
+        values.iter().copied().filter(|id| ids.contains(id)).collect()
+    }
+    };
+
+    let after = anyinput_core(quote!(), before);
+    assert_tokens_eq(&expected, &after);
+}
+
+#[test]
+fn range_input() {
+    let before = quote! {
+    pub fn sum(range: AnyRange, data: AnyArray<u32>) -> Result<u32, anyinput::RangeError> {
+        Ok(data[range].iter().sum())
+    }
+    };
+    let expected = quote! {
+    pub fn sum<AnyRange0, AnyArray1>(range: AnyRange0, data: AnyArray1) -> Result<u32, anyinput::RangeError>
+    where
+        AnyRange0: std::ops::RangeBounds<usize>,
+        AnyArray1: AsRef<[u32]>
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyRange<()>, AnyArray<()>,)>;
+            }
+        }
+        let data = data.as_ref();
+        let range = anyinput::resolve_range(range, data.len())?;
+        Ok(data[range].iter().sum())
+    }
+    };
+
+    let after = anyinput_core(quote!(len = data.len()), before);
+    assert_tokens_eq(&expected, &after);
+}
+
+#[test]
+fn iter_borrow_input() {
+    let before = quote! {
+    pub fn total(iter: AnyIterBorrow<usize, ExactSizeIterator>) -> usize {
+        iter.len() + iter.map(|x| *x).sum::<usize>()
+    }
+    };
+    let expected = quote! {
+    pub fn total<AnyIterBorrow0>(iter: AnyIterBorrow0) -> usize
+    where
+        AnyIterBorrow0: IntoIterator,
+        <AnyIterBorrow0 as IntoIterator>::Item: std::borrow::Borrow<usize>,
+        <AnyIterBorrow0 as IntoIterator>::IntoIter: ExactSizeIterator
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyIterBorrow<()>,)>;
+            }
+        }
+        let iter = anyinput::BorrowIter::<_, usize>::new(iter);
+        iter.len() + iter.map(|x| *x).sum::<usize>()
+    }
+    };
+
+    let after = anyinput_core(quote!(), before);
+    assert_tokens_eq(&expected, &after);
+}
+
+#[test]
+fn iter_multi_input() {
+    let before = quote! {
+    pub fn min_and_max(data: AnyIterMulti<usize>) -> (usize, usize) {
+        (data.iter().map(|x| *x).min().unwrap(), data.iter().map(|x| *x).max().unwrap())
+    }
+    };
+    let expected = quote! {
+    pub fn min_and_max<AnyIterMulti0>(data: AnyIterMulti0) -> (usize, usize)
+    where
+        AnyIterMulti0: IntoIterator,
+        <AnyIterMulti0 as IntoIterator>::Item: std::borrow::Borrow<usize>,
+        <AnyIterMulti0 as IntoIterator>::IntoIter: Clone
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyIterMulti<()>,)>;
+            }
+        }
+        let data = anyinput::MultiIter::<_, usize>::new(data);
+        (data.iter().map(|x| *x).min().unwrap(), data.iter().map(|x| *x).max().unwrap())
+    }
+    };
+
+    let after = anyinput_core(quote!(), before);
+    assert_tokens_eq(&expected, &after);
+}
+
+#[test]
+fn try_iter_input() {
+    let before = quote! {
+    pub fn total_len(lines: AnyTryIter<AnyString, std::io::Error>) -> Result<usize, std::io::Error> {
+        let mut total = 0;
+        for line in lines {
+            total += line?.as_ref().len();
+        }
+        Ok(total)
+    }
+    };
+    let expected = quote! {
+    pub fn total_len<AnyString0, AnyTryIter1>(lines: AnyTryIter1) -> Result<usize, std::io::Error>
+    where
+        AnyString0: AsRef<str>,
+        AnyTryIter1: IntoIterator,
+        <AnyTryIter1 as IntoIterator>::Item: anyinput::TryItem<AnyString0, std::io::Error>
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyString, AnyTryIter<(), ()>,)>;
+            }
+        }
+        let lines = anyinput::TryIter::<_, AnyString0, std::io::Error>::new(lines);
+        let mut total = 0;
+        for line in lines {
+            total += line?.as_ref().len();
+        }
+        Ok(total)
+    }
+    };
+
+    let after = anyinput_core(quote!(), before);
+    assert_tokens_eq(&expected, &after);
+}
+
+#[test]
+fn one_or_many_input() {
+    let before = quote! {
+    pub fn count_beds(paths: AnyOneOrMany<AnyPath>) -> usize {
+        paths.filter(|path| path.as_ref().extension() == Some("bed".as_ref())).count()
+    }
+    };
+    let expected = quote! {
+    pub fn count_beds<AnyPath0, AnyOneOrMany1>(paths: AnyOneOrMany1) -> usize
+    where
+        AnyPath0: AsRef<std::path::Path>,
+        AnyOneOrMany1: anyinput::OneOrMany<AnyPath0>
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyPath, AnyOneOrMany<()>,)>;
+            }
+        }
+        let paths = paths.into_many();
+        paths.filter(|path| path.as_ref().extension() == Some("bed".as_ref())).count()
+    }
+    };
+
+    let after = anyinput_core(quote!(), before);
+    assert_tokens_eq(&expected, &after);
+}
+
+#[test]
+fn display_input() {
+    let before = quote! {
+    pub fn label(name: AnyDisplay, value: AnyDisplay<Clone + Send>) -> String {
+        format!("{name}={value}")
+    }
+    };
+    let expected = quote! {
+    pub fn label<AnyDisplay0, AnyDisplay1>(name: AnyDisplay0, value: AnyDisplay1) -> String
+    where
+        AnyDisplay0: std::fmt::Display,
+        AnyDisplay1: std::fmt::Display + Clone + Send
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyDisplay,)>;
+            }
+        }
+        let value = anyinput::display_to_cow(&value);
+        let name = anyinput::display_to_cow(&name);
+        format!("{name}={value}")
+    }
+    };
+
+    let after = anyinput_core(quote!(), before);
+    assert_tokens_eq(&expected, &after);
+}
+
+#[test]
+fn duration_input() {
+    let before = quote! {
+    pub fn timeout_ms(timeout: AnyDuration) -> Result<u128, anyinput::DurationError> {
+        Ok(timeout.as_millis())
+    }
+    };
+    let expected = quote! {
+    pub fn timeout_ms<AnyDuration0>(timeout: AnyDuration0) -> Result<u128, anyinput::DurationError>
+    where
+        AnyDuration0: anyinput::ToDuration
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyDuration,)>;
+            }
+        }
+        let timeout = timeout.to_duration()?;
+        Ok(timeout.as_millis())
+    }
+    };
+
+    let after = anyinput_core(quote!(), before);
+    assert_tokens_eq(&expected, &after);
+}
+
+#[test]
+fn duration_input_result_alias() {
+    let before = quote! {
+    pub fn timeout_ms(timeout: AnyDuration) -> Res<u128> {
+        Ok(timeout.as_millis())
+    }
+    };
+    let expected = quote! {
+    pub fn timeout_ms<AnyDuration0>(timeout: AnyDuration0) -> Res<u128>
+    where
+        AnyDuration0: anyinput::ToDuration
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyDuration,)>;
+            }
+        }
+        let timeout = timeout.to_duration()?;
+        Ok(timeout.as_millis())
+    }
+    };
+
+    let after = anyinput_core(quote!(), before);
+    assert_tokens_eq(&expected, &after);
+}
+
+#[test]
+#[should_panic(
+    expected = "proc-macro-error API cannot be used outside of `entry_point` invocation, perhaps you forgot to annotate your #[proc_macro] function with `#[proc_macro_error]"
+)]
+fn duration_input_no_return_type() {
+    let before = quote! {
+    pub fn print_ms(timeout: AnyDuration) {
+        println!("{}", timeout.as_millis());
+    }
+    };
+    let _after = anyinput_core(quote!(), before);
+}
+
+#[test]
+fn arc_inputs() {
+    let before = quote! {
+    pub fn keep(name: AnyArc<str>, values: AnyArc<[AnyString], Send>) -> usize {
+        values.len()
+    }
+    };
+    let expected = quote! {
+    pub fn keep<AnyArc0, AnyString1, AnyArc2>(name: AnyArc0, values: AnyArc2) -> usize
+    where
+        AnyArc0: Into<std::sync::Arc<str> >,
+        AnyString1: AsRef<str>,
+        AnyArc2: Into<std::sync::Arc<[AnyString1]> > + Send
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyArc<()>, AnyString,)>;
+            }
+        }
+        let values = values.into();
+        let name = name.into();
+        values.len()
+    }
+    };
+
+    let after = anyinput_core(quote!(), before);
+    assert_tokens_eq(&expected, &after);
+}
+
+#[test]
+fn error_inputs() {
+    let before = quote! {
+    pub fn report(error: AnyError, context: AnyAnyhow) -> String {
+        format!("{error}: {context}")
+    }
+    };
+    let expected = quote! {
+    pub fn report<AnyError0, AnyAnyhow1>(error: AnyError0, context: AnyAnyhow1) -> String
+    where
+        AnyError0: Into<Box<dyn std::error::Error + Send + Sync> >,
+        AnyAnyhow1: anyinput::IntoAnyhow
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyError, AnyAnyhow,)>;
+            }
+        }
+        let context = context.into_anyhow();
+        let error = error.into();
+        format!("{error}: {context}")
+    }
+    };
+
+    let after = anyinput_core(quote!(), before);
+    assert_tokens_eq(&expected, &after);
+}
+
+#[test]
+fn socket_addr_input() {
+    let before = quote! {
+    pub fn connect(addr: AnySocketAddr) -> Result<std::net::TcpStream, std::io::Error> {
+        std::net::TcpStream::connect(&addr[..])
+    }
+    };
+    let expected = quote! {
+    pub fn connect<AnySocketAddr0>(addr: AnySocketAddr0) -> Result<std::net::TcpStream, std::io::Error>
+    where
+        AnySocketAddr0: std::net::ToSocketAddrs
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnySocketAddr,)>;
+            }
+        }
+        let addr: Vec<std::net::SocketAddr> = addr.to_socket_addrs()?.collect();
+        std::net::TcpStream::connect(&addr[..])
+    }
+    };
+
+    let after = anyinput_core(quote!(), before);
+    assert_tokens_eq(&expected, &after);
+}
+
+#[test]
+fn single_bound_without_type() {
+    let before = quote! {
+    pub fn total_len(s: AnyString<Clone>, p: AnyPath<Send>) -> usize {
+        s.len() + p.as_os_str().len()
+    }
+    };
+    let expected = quote! {
+    pub fn total_len<AnyString0, AnyPath1>(s: AnyString0, p: AnyPath1) -> usize
+    where
+        AnyString0: AsRef<str> + Clone,
+        AnyPath1: AsRef<std::path::Path> + Send
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyString, AnyPath,)>;
+            }
+        }
+        let p = p.as_ref();
+        let s = s.as_ref();
+        s.len() + p.as_os_str().len()
+    }
+    };
+
+    let after = anyinput_core(quote!(), before);
+    assert_tokens_eq(&expected, &after);
+}
+
+#[test]
+#[should_panic(
+    expected = "proc-macro-error API cannot be used outside of `entry_point` invocation, perhaps you forgot to annotate your #[proc_macro] function with `#[proc_macro_error]"
+)]
+fn one_bad_input_14() {
+    let before = quote! {
+    pub fn total(data: AnyTryIter<usize>) -> usize {
+        0
+    }
+    };
+    let _after = anyinput_core(quote!(), before);
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn one_ndarray_mut_input() {
+    let before = quote! {
+    pub fn normalize(a: AnyNdArrayMut<f32>) {
+        let sum = a.sum();
+        a.mapv_inplace(|x| x / sum);
+    }
+    };
+    let expected = quote! {
+    pub fn normalize<'any_nd_array_mut1, AnyNdArrayMut0>(a: AnyNdArrayMut0)
+    where
+        AnyNdArrayMut0: Into<ndarray::ArrayViewMut1<'any_nd_array_mut1, f32> >
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyNdArrayMut<()>,)>;
+            }
+        }
+        let mut a = a.into();
+        let sum = a.sum();
+        a.mapv_inplace(|x| x / sum);
+    }
+    };
+
+    let after = anyinput_core(quote!(), before);
+    assert_tokens_eq(&expected, &after);
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn understand_lifetime_parse() {
+    let a = Lifetime::new("'a", syn::__private::Span::call_site());
+    println!("a: {}", quote!(#a));
+    let b: Lifetime = parse_quote!('a);
+    println!("b: {}", quote!(#b));
+
+    let _generic_param: GenericParam = parse_quote!(S1: Into<ndarray::ArrayView1<'S0, S2>>);
+    println!("gp: {}", quote!(#_generic_param));
+    println!("done");
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn one_ndarray_usize_input() {
+    let before = quote! {
+    pub fn any_array_len(a: AnyNdArray<usize>) -> Result<usize, anyhow::Error> {
+        let len = a.len();
+        Ok(len)
+    }        };
+    let expected = quote! {
+    pub fn any_array_len<'any_nd_array1, AnyNdArray0>(
+        a: AnyNdArray0
+    ) -> Result<usize, anyhow::Error>
+    where
+        AnyNdArray0: Into<ndarray::ArrayView1<'any_nd_array1, usize> >
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyNdArray<()>,)>;
+            }
+        }
+        let a = a.into();
+        let len = a.len();
+        Ok(len)
+    }
+    };
+
+    let after = anyinput_core(quote!(), before);
+    assert_tokens_eq(&expected, &after);
+
+    // The lines are long enough that Clippy would like a comma after
+    // a:AnyNdArray0, but the macro doesn't do that because
+    // it doesn't know the line length.
+    pub fn any_array_len<'any_nd_array1, AnyNdArray0>(
+        a: AnyNdArray0,
+    ) -> Result<usize, anyhow::Error>
+    where
+        AnyNdArray0: Into<ndarray::ArrayView1<'any_nd_array1, usize>>,
+    {
+        let a = a.into();
+        let len = a.len();
+        Ok(len)
+    }
+    assert_eq!(any_array_len([1, 2, 3].as_ref()).unwrap(), 3);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn complex() {
+    let before = quote! {
+        pub fn complex_total(
+            a: usize,
+            b: AnyIter<Vec<AnyArray<AnyPath>>>,
+            c: AnyNdArray<usize>
+        ) -> Result<usize, anyhow::Error> {
+            let mut total = a + c.sum();
+            for vec in b {
+                for any_array in vec {
+                    let any_array = any_array.as_ref();
+                    for any_path in any_array.iter() {
+                        let any_path = any_path.as_ref();
+                        total += any_path.iter().count();
+                    }
+                }
+            }
+            Ok(total)
+            }
+    };
+    let expected = quote! {
+    pub fn complex_total<'any_nd_array4, AnyPath0, AnyArray1, AnyIter2, AnyNdArray3>(
+        a: usize,
+        b: AnyIter2,
+        c: AnyNdArray3
+    ) -> Result<usize, anyhow::Error>
+    where
+        AnyPath0: AsRef<std::path::Path>,
+        AnyArray1: AsRef<[AnyPath0]>,
+        AnyIter2: IntoIterator<Item = Vec<AnyArray1> >,
+        AnyNdArray3: Into<ndarray::ArrayView1<'any_nd_array4, usize> >
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyPath, AnyArray<()>, AnyIter<()>, AnyNdArray<()>,)>;
+            }
+        }
+        let c = c.into();
+        let b = b.into_iter();
+        let mut total = a + c.sum();
+        for vec in b {
+            for any_array in vec {
+                let any_array = any_array.as_ref();
+                for any_path in any_array.iter() {
+                    let any_path = any_path.as_ref();
+                    total += any_path.iter().count();
+                }
+            }
+        }
+        Ok(total)
+    }
+    };
+
+    let after = anyinput_core(quote!(), before);
+    assert_tokens_eq(&expected, &after);
+
+    pub fn complex_total<'any_nd_array4, AnyPath0, AnyArray1, AnyIter2, AnyNdArray3>(
+        a: usize,
+        b: AnyIter2,
+        c: AnyNdArray3,
+    ) -> Result<usize, anyhow::Error>
+    where
+        AnyPath0: AsRef<std::path::Path>,
+        AnyArray1: AsRef<[AnyPath0]>,
+        AnyIter2: IntoIterator<Item = Vec<AnyArray1>>,
+        AnyNdArray3: Into<ndarray::ArrayView1<'any_nd_array4, usize>>,
+    {
+        let c = c.into();
+        let b = b.into_iter();
+        let mut total = a + c.sum();
+        for vec in b {
+            for any_array in vec {
+                let any_array = any_array.as_ref();
+                for any_path in any_array.iter() {
+                    let any_path = any_path.as_ref();
+                    total += any_path.iter().count();
+                }
+            }
+        }
+        Ok(total)
+    }
+
+    assert_eq!(
+        complex_total(17, [vec![["one"]]], [1, 2, 3].as_ref()).unwrap(),
+        24
+    );
+}
+
+#[test]
+fn extra_bounds() {
+    let before = quote! {
+    pub fn first_last(iter: AnyIter<usize, ExactSizeIterator + DoubleEndedIterator>, s: AnyString<Clone + Default>) -> usize {
+        let len = iter.len();
+        let last = iter.rev().take(1).sum::<usize>();
+        len + last + s.len()
+    }
+    };
+    let expected = quote! {
+    pub fn first_last<AnyIter0, AnyString1>(iter: AnyIter0, s: AnyString1) -> usize
+    where
+        AnyIter0: IntoIterator<Item = usize>,
+        <AnyIter0 as IntoIterator>::IntoIter: ExactSizeIterator + DoubleEndedIterator,
+        AnyString1: AsRef<str> + Clone + Default
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyIter<()>, AnyString,)>;
+            }
+        }
+        let s = s.as_ref();
+        let iter = iter.into_iter();
+        let len = iter.len();
+        let last = iter.rev().take(1).sum::<usize>();
+        len + last + s.len()
+    }
+    };
+
+    let after = anyinput_core(quote!(), before);
+    assert_tokens_eq(&expected, &after);
+
+    pub fn first_last<AnyIter0, AnyString1>(iter: AnyIter0, s: AnyString1) -> usize
+    where
+        AnyIter0: IntoIterator<Item = usize>,
+        <AnyIter0 as IntoIterator>::IntoIter: ExactSizeIterator + DoubleEndedIterator,
+        AnyString1: AsRef<str> + Clone + Default,
+    {
+        let s = s.as_ref();
+        let iter = iter.into_iter();
+        let len = iter.len();
+        let last = iter.rev().take(1).sum::<usize>();
+        len + last + s.len()
+    }
+    assert_eq!(first_last(vec![1, 2, 3], "abc"), 9);
+}
+
+#[test]
+fn extra_bounds_with_send() {
+    let before = quote! {
+    pub fn any_array_len(a: AnyArray<AnyString, Clone>) -> usize {
+        a.len()
+    }
+    };
+    let expected = quote! {
+    pub fn any_array_len<AnyString0, AnyArray1>(a: AnyArray1) -> usize
+    where
+        AnyString0: AsRef<str> + Send + Sync,
+        AnyArray1: AsRef<[AnyString0]> + Clone + Send + Sync
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyString, AnyArray<()>,)>;
+            }
+        }
+        let a = a.as_ref();
+        a.len()
+    }
+    };
+
+    let after = anyinput_core(quote!(send), before);
+    assert_tokens_eq(&expected, &after);
+}
+
+#[test]
+#[should_panic(
+    expected = "proc-macro-error API cannot be used outside of `entry_point` invocation, perhaps you forgot to annotate your #[proc_macro] function with `#[proc_macro_error]"
+)]
+fn bad_extra_bounds() {
+    let before = quote! {
+    pub fn any_count(i: AnyIter<usize, 3>) -> usize {
+        i.count()
+    }
+    };
+    let _after = anyinput_core(quote!(), before);
+}
+
+#[test]
+fn only_and_except() {
+    let before = quote! {
+    pub fn count(paths: AnyIter<AnyPath>, s: AnyString) -> usize {
+        paths.count() + s.len()
+    }
+    };
+    let expected = quote! {
+    pub fn count<AnyIter0>(paths: AnyIter0, s: AnyString) -> usize
+    where
+        AnyIter0: IntoIterator<Item = AnyPath>
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyIter<()>,)>;
+            }
+        }
+        let paths = paths.into_iter();
+        paths.count() + s.len()
+    }
+    };
+
+    let after = anyinput_core(quote!(only(AnyIter)), before.clone());
+    assert_tokens_eq(&expected, &after);
+    let after = anyinput_core(quote!(except(AnyPath, AnyString)), before);
+    assert_tokens_eq(&expected, &after);
+
+    #[derive(Debug)]
+    pub struct AnyPath;
+    pub type AnyString = &'static str;
+    pub fn count<AnyIter0>(paths: AnyIter0, s: AnyString) -> usize
+    where
+        AnyIter0: IntoIterator<Item = AnyPath>,
+    {
+        let paths = paths.into_iter();
+        paths.count() + s.len()
+    }
+    assert_eq!(count([AnyPath, AnyPath], "abc"), 5);
+}
+
+#[test]
+fn except_shadowed() {
+    let before = quote! {
+    pub fn count<AnyPath>(paths: AnyIter<AnyPath>) -> usize {
+        struct AnyString;
+        paths.count()
+    }
+    };
+    let expected = quote! {
+    pub fn count<AnyPath, AnyIter0>(paths: AnyIter0) -> usize
+    where
+        AnyIter0: IntoIterator<Item = AnyPath>
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyIter<()>,)>;
+            }
+        }
+        let paths = paths.into_iter();
+        struct AnyString;
+        paths.count()
+    }
+    };
+
+    let after = anyinput_core(quote!(except(AnyPath, AnyString)), before);
+    assert_tokens_eq(&expected, &after);
+}
+
+#[test]
+#[should_panic(
+    expected = "proc-macro-error API cannot be used outside of `entry_point` invocation, perhaps you forgot to annotate your #[proc_macro] function with `#[proc_macro_error]"
+)]
+fn shadowed_special() {
+    let before = quote! {
+    pub fn count<AnyPath>(paths: AnyIter<AnyPath>) -> usize {
+        paths.count()
+    }
+    };
+    let _after = anyinput_core(quote!(), before);
+}
+
+#[test]
+fn except_shadowed_per_input() {
+    let before = quote! {
+    pub fn count<AnyPath>(#[anyinput(except(AnyPath))] paths: AnyIter<AnyPath>) -> usize {
+        paths.count()
+    }
+    };
+    let expected = quote! {
+    pub fn count<AnyPath, AnyIter0>(paths: AnyIter0) -> usize
+    where
+        AnyIter0: IntoIterator<Item = AnyPath>
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyIter<()>,)>;
+            }
+        }
+        let paths = paths.into_iter();
+        paths.count()
+    }
+    };
+
+    let after = anyinput_core(quote!(), before);
+    assert_tokens_eq(&expected, &after);
+}
+
+#[test]
+fn body_items_shadow_nothing() {
+    let before = quote! {
+    pub fn count(paths: AnyIter<AnyPath>) -> usize {
+        use anyinput::{AnyIter, AnyPath};
+        struct AnyString;
+        paths.count()
+    }
+    };
+    let expected = quote! {
+    pub fn count<AnyPath0, AnyIter1>(paths: AnyIter1) -> usize
+    where
+        AnyPath0: AsRef<std::path::Path>,
+        AnyIter1: IntoIterator<Item = AnyPath0>
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyPath, AnyIter<()>,)>;
+            }
+        }
+        let paths = paths.into_iter();
+        use anyinput::{AnyIter, AnyPath};
+        struct AnyString;
+        paths.count()
+    }
+    };
+
+    let after = anyinput_core(quote!(), before);
+    assert_tokens_eq(&expected, &after);
+}
+
+#[test]
+fn unknown_special() {
+    let after = anyinput_core(
+        quote!(only(AnyStr)),
+        quote!(
+            fn f() {}
+        ),
+    );
+    assert!(after.to_string().contains("Unknown special 'AnyStr'."));
+}
+
+#[test]
+fn aliases() {
+    let before = quote! {
+    pub fn total_len(texts: Seq<Text>, path: FilePath) -> usize {
+        texts.map(|text| text.as_ref().len()).sum::<usize>() + path.iter().count()
+    }
+    };
+    let expected = quote! {
+    pub fn total_len<AnyString0, AnyIter1, AnyPath2>(texts: AnyIter1, path: AnyPath2) -> usize
+    where
+        AnyString0: AsRef<str>,
+        AnyIter1: IntoIterator<Item = AnyString0>,
+        AnyPath2: AsRef<std::path::Path>
+    {
+        let path = path.as_ref();
+        let texts = texts.into_iter();
+        texts.map(|text| text.as_ref().len()).sum::<usize>() + path.iter().count()
+    }
+    };
+
+    let after = anyinput_core(
+        quote!(alias(Text = AnyString, FilePath = AnyPath, Seq = AnyIter)),
+        before,
+    );
+    assert_tokens_eq(&expected, &after);
+
+    pub fn total_len<AnyString0, AnyIter1, AnyPath2>(texts: AnyIter1, path: AnyPath2) -> usize
+    where
+        AnyString0: AsRef<str>,
+        AnyIter1: IntoIterator<Item = AnyString0>,
+        AnyPath2: AsRef<std::path::Path>,
+    {
+        let path = path.as_ref();
+        let texts = texts.into_iter();
+        texts.map(|text| text.as_ref().len()).sum::<usize>() + path.iter().count()
+    }
+    assert_eq!(total_len(["a", "bb"], "c/d"), 5);
+}
+
+#[test]
+fn qualified_paths() {
+    let before = quote! {
+    pub fn total_len(texts: anyinput::AnyIter<::anyinput::AnyString>, other: other::AnyString) -> usize {
+        texts.map(|text| text.as_ref().len()).sum()
+    }
+    };
+    let expected = quote! {
+    pub fn total_len<AnyString0, AnyIter1>(texts: AnyIter1, other: other::AnyString) -> usize
+    where
+        AnyString0: AsRef<str>,
+        AnyIter1: IntoIterator<Item = AnyString0>
+    {
+        let texts = texts.into_iter();
+        texts.map(|text| text.as_ref().len()).sum()
+    }
+    };
+
+    let after = anyinput_core(quote!(), before);
+    assert_tokens_eq(&expected, &after);
+}
+
+// Sums an iterator on another thread, so the iterator must be 'Send' and ''static'.
+fn sum_on_thread<I: Iterator<Item = usize> + Send + 'static>(iter: I) -> usize {
+    std::thread::spawn(move || iter.sum()).join().unwrap()
+}
+
+#[test]
+fn send_static() {
+    let before = quote! {
+    pub fn len_sum(iter: AnyIter<AnyString>) -> usize {
+        sum_on_thread(iter.map(|s| s.as_ref().len()))
+    }
+    };
+    let expected = quote! {
+    pub fn len_sum<AnyString0, AnyIter1>(iter: AnyIter1) -> usize
+    where
+        AnyString0: AsRef<str> + Send + Sync + 'static,
+        AnyIter1: IntoIterator<Item = AnyString0> + Send + Sync + 'static,
+        <AnyIter1 as IntoIterator>::IntoIter: Send + Sync + 'static
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyString, AnyIter<()>,)>;
+            }
+        }
+        let iter = iter.into_iter();
+        sum_on_thread(iter.map(|s| s.as_ref().len()))
+    }
+    };
+
+    let after = anyinput_core(quote!(send, static), before);
+    assert_tokens_eq(&expected, &after);
+
+    pub fn len_sum<AnyString0, AnyIter1>(iter: AnyIter1) -> usize
+    where
+        AnyString0: AsRef<str> + Send + Sync + 'static,
+        AnyIter1: IntoIterator<Item = AnyString0> + Send + Sync + 'static,
+        <AnyIter1 as IntoIterator>::IntoIter: Send + Sync + 'static,
+    {
+        let iter = iter.into_iter();
+        sum_on_thread(iter.map(|s| s.as_ref().len()))
+    }
+    assert_eq!(len_sum(vec!["a", "bb", "ccc"]), 6);
+}
+
+#[test]
+fn send_per_input() {
+    let before = quote! {
+    pub fn count_plus_len(#[anyinput(send, static)] iter: AnyIter<usize>, s: AnyString) -> usize {
+        sum_on_thread(iter) + s.len()
+    }
+    };
+    let expected = quote! {
+    pub fn count_plus_len<AnyIter0, AnyString1>(iter: AnyIter0, s: AnyString1) -> usize
+    where
+        AnyIter0: IntoIterator<Item = usize> + Send + Sync + 'static,
+        <AnyIter0 as IntoIterator>::IntoIter: Send + Sync + 'static,
+        AnyString1: AsRef<str>
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyIter<()>, AnyString,)>;
+            }
+        }
+        let s = s.as_ref();
+        let iter = iter.into_iter();
+        sum_on_thread(iter) + s.len()
+    }
+    };
+
+    let after = anyinput_core(quote!(), before);
+    assert_tokens_eq(&expected, &after);
+
+    pub fn count_plus_len<AnyIter0, AnyString1>(iter: AnyIter0, s: AnyString1) -> usize
+    where
+        AnyIter0: IntoIterator<Item = usize> + Send + Sync + 'static,
+        <AnyIter0 as IntoIterator>::IntoIter: Send + Sync + 'static,
+        AnyString1: AsRef<str>,
+    {
+        let s = s.as_ref();
+        let iter = iter.into_iter();
+        sum_on_thread(iter) + s.len()
+    }
+    assert_eq!(count_plus_len(1..=3, "abc"), 9);
+}
+
+#[test]
+#[should_panic(
+    expected = "proc-macro-error API cannot be used outside of `entry_point` invocation, perhaps you forgot to annotate your #[proc_macro] function with `#[proc_macro_error]"
+)]
+fn bad_input_option() {
+    let before = quote! {
+    pub fn any_str_len(#[anyinput(sned)] s: AnyString) -> usize {
+        s.len()
+    }
+    };
+    let _after = anyinput_core(quote!(), before);
+}
+
+#[test]
+fn doc_write() -> Result<(), anyhow::Error> {
+    let before = quote! {
+    fn len_plus_2(s: AnyString) -> Result<usize, anyhow::Error> {
+        Ok(s.len()+2)
+    }        };
+    let after = anyinput_core(quote!(), before);
+    println!("after: {}", quote! { #after});
+    let expected = quote! {
+    fn len_plus_2<AnyString0>(s: AnyString0) -> Result<usize, anyhow::Error>
+    where
+        AnyString0: AsRef<str>
+    {
+        {
+            #[allow(unused_imports)]
+            use anyinput::*;
+            {
+                #[allow(unused_imports)]
+                use self::*;
+                let _: ::core::marker::PhantomData<(AnyString,)>;
+            }
+        }
+        let s = s.as_ref();
+        Ok(s.len() + 2)
+    }
+    };
+    assert_tokens_eq(&expected, &after);
+
+    fn len_plus_2<AnyString0>(s: AnyString0) -> Result<usize, anyhow::Error>
+    where
+        AnyString0: AsRef<str>,
+    {
+        let s = s.as_ref();
+        Ok(s.len() + 2)
+    }
+    assert_eq!(len_plus_2("hello")?, 7);
+
+    Ok(())
+}
+
+#[test]
+#[should_panic(
+    expected = "proc-macro-error API cannot be used outside of `entry_point` invocation, perhaps you forgot to annotate your #[proc_macro] function with `#[proc_macro_error]"
+)]
+fn one_bad_input_1() {
+    let before = quote! {
+    pub fn any_str_len(s: AnyIter<AnyString, Clone, usize>) -> Result<usize, anyhow::Error> {
+        let len = s.len();
+        Ok(len)
+    }
+       };
+    let _after = anyinput_core(quote!(), before);
+}
+
+#[test]
+#[should_panic(
+    expected = "proc-macro-error API cannot be used outside of `entry_point` invocation, perhaps you forgot to annotate your #[proc_macro] function with `#[proc_macro_error]"
+)]
+fn one_bad_input_2() {
+    let before = quote! {
+    pub fn any_str_len(s: AnyIter<3>) -> Result<usize, anyhow::Error> {
+        let len = s.len();
+        Ok(len)
+    }
+       };
+    let _after = anyinput_core(quote!(), before);
+}
+
+// syn rejects "AnyIter(AnyString)" as a type, so the macro returns a compile error.
+#[test]
+fn one_bad_input_3() {
+    let before = quote! {
+    pub fn any_str_len(s: AnyIter(AnyString)) {
+        s.len()
+    }
+       };
+    let after = anyinput_core(quote!(), before);
+    assert!(after.to_string().contains("compile_error"));
+}
+
+#[test]
+#[should_panic(
+    expected = "proc-macro-error API cannot be used outside of `entry_point` invocation, perhaps you forgot to annotate your #[proc_macro] function with `#[proc_macro_error]"
+)]
 fn one_bad_input_4() {
     let before = quote! {
     pub fn any_str_len(s: AnyArray) -> Result<usize, anyhow::Error> {
@@ -730,6 +2121,71 @@ fn one_bad_input_8() {
     let _after = anyinput_core(quote!(), before);
 }
 
+#[test]
+#[should_panic(
+    expected = "proc-macro-error API cannot be used outside of `entry_point` invocation, perhaps you forgot to annotate your #[proc_macro] function with `#[proc_macro_error]"
+)]
+fn one_bad_input_9() {
+    let before = quote! {
+    pub fn any_bytes_len(b: AnyBytes<u8>) -> usize {
+        b.len()
+    }
+       };
+    let _after = anyinput_core(quote!(), before);
+}
+
+#[test]
+#[should_panic(
+    expected = "proc-macro-error API cannot be used outside of `entry_point` invocation, perhaps you forgot to annotate your #[proc_macro] function with `#[proc_macro_error]"
+)]
+fn one_bad_input_10() {
+    let before = quote! {
+    pub fn print_len(s: AnyCStr) {
+        println!("{}", s.to_bytes().len());
+    }
+       };
+    let _after = anyinput_core(quote!(), before);
+}
+
+#[test]
+#[should_panic(
+    expected = "proc-macro-error API cannot be used outside of `entry_point` invocation, perhaps you forgot to annotate your #[proc_macro] function with `#[proc_macro_error]"
+)]
+fn one_bad_input_11() {
+    let before = quote! {
+    pub fn port(x: AnyTryInto<u16>) -> anyhow::Result<u16> {
+        Ok(x)
+    }
+       };
+    let _after = anyinput_core(quote!(), before);
+}
+
+#[test]
+#[should_panic(
+    expected = "proc-macro-error API cannot be used outside of `entry_point` invocation, perhaps you forgot to annotate your #[proc_macro] function with `#[proc_macro_error]"
+)]
+fn one_bad_input_12() {
+    let before = quote! {
+    pub fn count(map: AnyMap<AnyString>) -> usize {
+        map.count()
+    }
+       };
+    let _after = anyinput_core(quote!(), before);
+}
+
+#[test]
+#[should_panic(
+    expected = "proc-macro-error API cannot be used outside of `entry_point` invocation, perhaps you forgot to annotate your #[proc_macro] function with `#[proc_macro_error]"
+)]
+fn one_bad_input_13() {
+    let before = quote! {
+    pub fn sum(data: AnyArray<u32>, range: AnyRange) -> Result<u32, anyinput::RangeError> {
+        Ok(data[range].iter().sum())
+    }
+       };
+    let _after = anyinput_core(quote!(), before);
+}
+
 #[test]
 fn see_bed_reader() {
     let before = quote! {
@@ -801,7 +2257,7 @@ fn conversion_combinations() {
             println!("hello world")
         }
     );
-    assert_eq!(string_of_code1, "fn hello() { println! (\"hello world\") }");
+    assert_eq!(string_of_code1, "fn hello() { println!(\"hello world\") }");
 
     // Tokens to string-of-code & string-of-tokens
     assert_eq!(
@@ -0,0 +1,28 @@
+// Runtime support for AnyAnyhow, which requires the `anyhow` feature. The `#[anyinput]` macro bounds AnyAnyhow
+// inputs by `IntoAnyhow` and converts them with `.into_anyhow()`.
+
+use std::error::Error;
+
+/// Converts anything that AnyError accepts, for example, an `io::Error` or a `String` message, into an
+/// `anyhow::Error`. Requires the `anyhow` feature.
+///
+/// The `anyhow::Error` holds the input as a `Box<dyn Error + Send + Sync>`, so to downcast it, first downcast to
+/// that box, for example, `error.downcast_ref::<Box<dyn Error + Send + Sync>>()`.
+///
+/// # Example
+/// ```
+/// use anyinput::IntoAnyhow;
+///
+/// let error = "file is empty".into_anyhow();
+/// assert_eq!(error.to_string(), "file is empty");
+/// ```
+pub trait IntoAnyhow {
+    /// Converts into an `anyhow::Error`.
+    fn into_anyhow(self) -> anyhow::Error;
+}
+
+impl<E: Into<Box<dyn Error + Send + Sync>>> IntoAnyhow for E {
+    fn into_anyhow(self) -> anyhow::Error {
+        anyhow::Error::from_boxed(self.into())
+    }
+}
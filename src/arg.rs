@@ -0,0 +1,221 @@
+// Runtime support for mixed elements. The `args!` macro wraps each element in an `Arg`, so a `&str` and a
+// `String` can share one `Vec`. `Arg` implements the bounds of the string-like and path-like AnyInputs.
+
+use crate::{DurationError, Single, ToCStr, ToDuration};
+use std::borrow::{Borrow, Cow};
+use std::error::Error;
+use std::ffi::{CStr, NulError, OsStr};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::net::ToSocketAddrs;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Creates a `Vec` of [`Arg`]s from borrowed and owned elements, for example, `&str`s and `String`s, mixed.
+///
+/// A `Vec<Arg<str>>` works as, for example, an `AnyArray<AnyString>`, `AnyArray<AnyPath>`, or
+/// `AnyIter<AnyCow<str>>`. For paths, for example, `Path::new("a")` and `PathBuf`s, it's a `Vec<Arg<Path>>`.
+///
+/// # Example
+/// ```
+/// use anyinput::{anyinput, args};
+///
+/// #[anyinput]
+/// fn total_len(names: AnyArray<AnyString>) -> usize {
+///     names.iter().map(|name| name.as_ref().len()).sum()
+/// }
+///
+/// let owned = "bc".to_string();
+/// assert_eq!(total_len(args!["a", owned]), 3);
+/// ```
+#[macro_export]
+macro_rules! args {
+    ($($arg:expr),* $(,)?) => {
+        ::std::vec![$($crate::Arg::new($arg)),*]
+    };
+}
+
+/// A borrowed or owned element, for example, a `&str` or a `String`, as created by [`args!`](crate::args!).
+///
+/// An `Arg<str>` works as any string-like, path-like, byte-like, OS-string-like, or C-string-like AnyInput and as
+/// an `AnyCow<str>`, `AnyInto<String>`, `AnyArc<str>`, `AnyDuration`, `AnyError`, `AnyAnyhow`, or `AnySocketAddr`.
+/// An `Arg<Path>` works as any path-like or OS-string-like AnyInput and as an `AnyCow<Path>`, `AnyInto<PathBuf>`,
+/// or `AnyArc<Path>`. Because an `Arg<B>` borrows as `B` and compares and hashes as `B`, it also works as an item
+/// of `AnyIterBorrow<B>` or `AnyIterMulti<B>` and as an element of `AnySet`.
+///
+/// # Example
+/// ```
+/// use anyinput::Arg;
+/// use std::path::Path;
+///
+/// let arg = Arg::<str>::new("a.bed".to_string());
+/// assert_eq!(&*arg, "a.bed");
+/// let path: &Path = arg.as_ref();
+/// assert_eq!(path.extension(), Some("bed".as_ref()));
+/// ```
+pub struct Arg<'a, B: ?Sized + ToOwned>(Cow<'a, B>);
+
+impl<'a, B: ?Sized + ToOwned> Arg<'a, B> {
+    /// Creates the element from anything that converts into a `Cow<B>`, for example, a `&str` or a `String`.
+    pub fn new(value: impl Into<Cow<'a, B>>) -> Self {
+        Arg(value.into())
+    }
+
+    /// Returns the element as a `Cow`.
+    pub fn into_cow(self) -> Cow<'a, B> {
+        self.0
+    }
+}
+
+impl<B: ?Sized + ToOwned> Deref for Arg<'_, B> {
+    type Target = B;
+
+    fn deref(&self) -> &B {
+        &self.0
+    }
+}
+
+impl<B: ?Sized + ToOwned> AsRef<B> for Arg<'_, B> {
+    fn as_ref(&self) -> &B {
+        &self.0
+    }
+}
+
+impl<B: ?Sized + ToOwned> Borrow<B> for Arg<'_, B> {
+    fn borrow(&self) -> &B {
+        &self.0
+    }
+}
+
+// So that iterating a borrowed Vec of Args, for example, "&args![..]", gives items that borrow as str or Path.
+impl Borrow<str> for &Arg<'_, str> {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<Path> for &Arg<'_, Path> {
+    fn borrow(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl AsRef<Path> for Arg<'_, str> {
+    fn as_ref(&self) -> &Path {
+        Path::new(&*self.0)
+    }
+}
+
+impl AsRef<OsStr> for Arg<'_, str> {
+    fn as_ref(&self) -> &OsStr {
+        OsStr::new(&*self.0)
+    }
+}
+
+impl AsRef<[u8]> for Arg<'_, str> {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl AsRef<OsStr> for Arg<'_, Path> {
+    fn as_ref(&self) -> &OsStr {
+        self.0.as_os_str()
+    }
+}
+
+impl<B: ToCStr + ?Sized + ToOwned> ToCStr for Arg<'_, B> {
+    fn to_c_str(&self) -> Result<Cow<'_, CStr>, NulError> {
+        (*self.0).to_c_str()
+    }
+}
+
+impl<B: ToDuration + ?Sized + ToOwned> ToDuration for Arg<'_, B> {
+    fn to_duration(&self) -> Result<Duration, DurationError> {
+        (*self.0).to_duration()
+    }
+}
+
+impl<B: ToSocketAddrs + ?Sized + ToOwned> ToSocketAddrs for Arg<'_, B> {
+    type Iter = B::Iter;
+
+    fn to_socket_addrs(&self) -> io::Result<B::Iter> {
+        (*self.0).to_socket_addrs()
+    }
+}
+
+impl<'a, B: ?Sized + ToOwned> From<Arg<'a, B>> for Cow<'a, B> {
+    fn from(arg: Arg<'a, B>) -> Self {
+        arg.0
+    }
+}
+
+impl From<Arg<'_, str>> for String {
+    fn from(arg: Arg<'_, str>) -> Self {
+        arg.0.into_owned()
+    }
+}
+
+impl From<Arg<'_, Path>> for PathBuf {
+    fn from(arg: Arg<'_, Path>) -> Self {
+        arg.0.into_owned()
+    }
+}
+
+// Also gives IntoAnyhow, so an Arg<str> message works as an AnyError or AnyAnyhow.
+impl From<Arg<'_, str>> for Box<dyn Error + Send + Sync> {
+    fn from(arg: Arg<'_, str>) -> Self {
+        arg.0.into()
+    }
+}
+
+impl From<Arg<'_, str>> for Arc<str> {
+    fn from(arg: Arg<'_, str>) -> Self {
+        Arc::from(arg.0)
+    }
+}
+
+impl From<Arg<'_, Path>> for Arc<Path> {
+    fn from(arg: Arg<'_, Path>) -> Self {
+        Arc::from(arg.0)
+    }
+}
+
+impl Single for Arg<'_, str> {}
+impl Single for Arg<'_, Path> {}
+
+impl<B: ?Sized + ToOwned> Clone for Arg<'_, B> {
+    fn clone(&self) -> Self {
+        Arg(self.0.clone())
+    }
+}
+
+impl<B: fmt::Debug + ?Sized + ToOwned> fmt::Debug for Arg<'_, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (*self.0).fmt(f)
+    }
+}
+
+impl<B: fmt::Display + ?Sized + ToOwned> fmt::Display for Arg<'_, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (*self.0).fmt(f)
+    }
+}
+
+// Comparing and hashing as B agrees with Borrow<B>, so, for example, a HashSet<Arg<str>> can be searched by &str.
+impl<B: PartialEq + ?Sized + ToOwned> PartialEq for Arg<'_, B> {
+    fn eq(&self, other: &Self) -> bool {
+        *self.0 == *other.0
+    }
+}
+
+impl<B: Eq + ?Sized + ToOwned> Eq for Arg<'_, B> {}
+
+impl<B: Hash + ?Sized + ToOwned> Hash for Arg<'_, B> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (*self.0).hash(state)
+    }
+}
@@ -0,0 +1,121 @@
+// Runtime support for AnyIterBorrow. The `#[anyinput]` macro converts AnyIterBorrow inputs into a `BorrowIter`,
+// whose items dereference to `&T` whether the input yields `T` or `&T`.
+
+use std::borrow::Borrow;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+/// An iterator whose items can be read as `&T`, made from an iterator whose items are `T`, `&T`, or anything else
+/// that borrows as `T`.
+///
+/// # Example
+/// ```
+/// use anyinput::BorrowIter;
+///
+/// let vec = vec![1, 2, 3];
+/// let total: usize = BorrowIter::<_, usize>::new(&vec).map(|x| *x).sum();
+/// assert_eq!(total, 6);
+/// let total: usize = BorrowIter::<_, usize>::new(1..4).map(|x| *x).sum();
+/// assert_eq!(total, 6);
+/// ```
+pub struct BorrowIter<I, T: ?Sized> {
+    iter: I,
+    phantom: PhantomData<fn(&T)>,
+}
+
+impl<I: Iterator, T: ?Sized> BorrowIter<I, T>
+where
+    I::Item: Borrow<T>,
+{
+    /// Creates the iterator from anything iterator-like.
+    pub fn new(into_iter: impl IntoIterator<IntoIter = I>) -> Self {
+        BorrowIter {
+            iter: into_iter.into_iter(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I: Iterator, T: ?Sized> Iterator for BorrowIter<I, T>
+where
+    I::Item: Borrow<T>,
+{
+    type Item = Borrowed<I::Item, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(Borrowed::new)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator, T: ?Sized> DoubleEndedIterator for BorrowIter<I, T>
+where
+    I::Item: Borrow<T>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(Borrowed::new)
+    }
+}
+
+impl<I: ExactSizeIterator, T: ?Sized> ExactSizeIterator for BorrowIter<I, T> where I::Item: Borrow<T>
+{}
+
+impl<I: Clone, T: ?Sized> Clone for BorrowIter<I, T> {
+    fn clone(&self) -> Self {
+        BorrowIter {
+            iter: self.iter.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I: fmt::Debug, T: ?Sized> fmt::Debug for BorrowIter<I, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("BorrowIter").field(&self.iter).finish()
+    }
+}
+
+/// An item of a [`BorrowIter`]. It dereferences to `&T`, so, for example, `*item` reads a `usize` and
+/// `item.len()` calls a method of `T`.
+pub struct Borrowed<B, T: ?Sized> {
+    value: B,
+    phantom: PhantomData<fn(&T)>,
+}
+
+impl<B: Borrow<T>, T: ?Sized> Borrowed<B, T> {
+    fn new(value: B) -> Self {
+        Borrowed {
+            value,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the item as the input iterator yielded it, for example, a `&T`.
+    pub fn into_inner(self) -> B {
+        self.value
+    }
+}
+
+impl<B: Borrow<T>, T: ?Sized> Deref for Borrowed<B, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.value.borrow()
+    }
+}
+
+impl<B: Borrow<T>, T: ?Sized> Borrow<T> for Borrowed<B, T> {
+    fn borrow(&self) -> &T {
+        self.value.borrow()
+    }
+}
+
+impl<B: Borrow<T>, T: fmt::Debug + ?Sized> fmt::Debug for Borrowed<B, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
@@ -0,0 +1,53 @@
+// Runtime support for AnyCStr. The `#[anyinput]` macro bounds AnyCStr inputs by `ToCStr` and
+// converts them with `.to_c_str()?`.
+
+use std::borrow::Cow;
+use std::ffi::{CStr, CString, NulError};
+
+/// Converts a C-string-like or string-like thing into a `Cow<CStr>`.
+///
+/// `&CStr` and `CString` convert without allocating or failing. `&str` and `String` are copied into a new
+/// `CString`, which fails with a [`NulError`] if the text contains an interior NUL byte.
+///
+/// # Example
+/// ```
+/// use anyinput::ToCStr;
+///
+/// assert_eq!(c"hello".to_c_str().unwrap().to_bytes(), b"hello");
+/// assert_eq!("hello".to_c_str().unwrap().to_bytes(), b"hello");
+/// assert!("hel\0lo".to_c_str().is_err());
+/// ```
+pub trait ToCStr {
+    /// Borrows or creates the `CStr`.
+    fn to_c_str(&self) -> Result<Cow<'_, CStr>, NulError>;
+}
+
+impl ToCStr for CStr {
+    fn to_c_str(&self) -> Result<Cow<'_, CStr>, NulError> {
+        Ok(Cow::Borrowed(self))
+    }
+}
+
+impl ToCStr for CString {
+    fn to_c_str(&self) -> Result<Cow<'_, CStr>, NulError> {
+        Ok(Cow::Borrowed(self.as_c_str()))
+    }
+}
+
+impl ToCStr for str {
+    fn to_c_str(&self) -> Result<Cow<'_, CStr>, NulError> {
+        CString::new(self).map(Cow::Owned)
+    }
+}
+
+impl ToCStr for String {
+    fn to_c_str(&self) -> Result<Cow<'_, CStr>, NulError> {
+        self.as_str().to_c_str()
+    }
+}
+
+impl<T: ToCStr + ?Sized> ToCStr for &T {
+    fn to_c_str(&self) -> Result<Cow<'_, CStr>, NulError> {
+        (**self).to_c_str()
+    }
+}
@@ -0,0 +1,79 @@
+// Runtime support for AnyDisplay. The `#[anyinput]` macro bounds AnyDisplay inputs by `Display` and converts them
+// with `display_to_cow(&..)`, which borrows string inputs and formats everything else.
+
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::mem;
+
+/// Converts anything that implements `Display` into a `Cow<str>`.
+///
+/// `str`, `&str`, `String`, `&String`, and `Cow<str>` are borrowed without allocating. Anything else, for example,
+/// a number, a `char`, or an enum, is formatted into a new `String`.
+///
+/// # Soundness
+///
+/// Rust can't yet choose code by type without `'static` bounds, so this function uses `unsafe`. It compares the
+/// `TypeId` of `T`, with every lifetime taken as `'static`, to those of the five borrowed types. On a match, `T` is
+/// that type up to lifetimes, so the reference is cast to it. Only lifetimes change, and they only shorten: the
+/// result borrows `value`, and any reference inside `T`, for example, the `&'a str` of `T = &'a str`, outlives
+/// `value`. Each borrowed type has its own test, which CI runs under Miri.
+///
+/// # Example
+/// ```
+/// use anyinput::display_to_cow;
+/// use std::borrow::Cow;
+///
+/// assert!(matches!(display_to_cow("label"), Cow::Borrowed("label")));
+/// assert_eq!(display_to_cow(&5), "5");
+/// ```
+pub fn display_to_cow<T: Display + ?Sized>(value: &T) -> Cow<'_, str> {
+    let type_id = type_id_ignoring_lifetimes::<T>();
+    // SAFETY: Each cast is to the type that `T` was just found to be, up to lifetimes. The lifetimes only
+    // shorten: `value` borrows `T` for the result's lifetime, so any reference inside `T` outlives the result.
+    unsafe {
+        if type_id == TypeId::of::<str>() {
+            return Cow::Borrowed(mem::transmute_copy::<&T, &str>(&value));
+        }
+        if type_id == TypeId::of::<&str>() {
+            return Cow::Borrowed(*mem::transmute_copy::<&T, &&str>(&value));
+        }
+        if type_id == TypeId::of::<String>() {
+            return Cow::Borrowed(mem::transmute_copy::<&T, &String>(&value));
+        }
+        if type_id == TypeId::of::<&String>() {
+            return Cow::Borrowed(*mem::transmute_copy::<&T, &&String>(&value));
+        }
+        if type_id == TypeId::of::<Cow<str>>() {
+            return Cow::Borrowed(mem::transmute_copy::<&T, &Cow<str>>(&value));
+        }
+    }
+    Cow::Owned(value.to_string())
+}
+
+// `TypeId::of` needs a `'static` type, but inputs often borrow, for example, `&'a str`. Asking a `'static` trait
+// object for the id of `PhantomData<T>` gives the id of `T` with every lifetime taken as `'static`.
+// (This is the technique of the `typeid` crate.)
+trait NonStaticAny {
+    fn type_id(&self) -> TypeId
+    where
+        Self: 'static;
+}
+
+impl<T: ?Sized> NonStaticAny for PhantomData<T> {
+    fn type_id(&self) -> TypeId
+    where
+        Self: 'static,
+    {
+        TypeId::of::<T>()
+    }
+}
+
+fn type_id_ignoring_lifetimes<T: ?Sized>() -> TypeId {
+    let phantom = PhantomData::<T>;
+    let phantom: &dyn NonStaticAny = &phantom;
+    // SAFETY: `PhantomData<T>` holds no data, so no reference of `T` is ever used with the longer lifetime.
+    let phantom: &(dyn NonStaticAny + 'static) = unsafe { mem::transmute(phantom) };
+    phantom.type_id()
+}
@@ -0,0 +1,140 @@
+// Runtime support for AnyDuration. The `#[anyinput]` macro bounds AnyDuration inputs by `ToDuration` and
+// converts them with `.to_duration()?`.
+
+use std::fmt;
+use std::time::Duration;
+
+/// Converts a duration-like thing into a `Duration`.
+///
+/// A `Duration` is used as is. Numbers, for example, `5` or `0.25`, are seconds. Strings are a number and an
+/// optional unit, for example, `"250ms"`, `"1.5s"`, `"2 min"`, or `"5"` (seconds). The units are `ns`, `us` (or
+/// `µs`), `ms`, `s`, `m` (or `min`), and `h`. A string that doesn't parse, or a number that is negative or too
+/// large, fails with a [`DurationError`].
+///
+/// # Example
+/// ```
+/// use anyinput::ToDuration;
+/// use std::time::Duration;
+///
+/// assert_eq!("250ms".to_duration(), Ok(Duration::from_millis(250)));
+/// assert_eq!(1.5.to_duration(), Ok(Duration::from_millis(1500)));
+/// assert!("soon".to_duration().is_err());
+/// ```
+pub trait ToDuration {
+    /// Converts into a `Duration`.
+    fn to_duration(&self) -> Result<Duration, DurationError>;
+}
+
+/// The error from [`ToDuration`] when an input isn't a valid duration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DurationError {
+    /// The text isn't a number and an optional unit, for example, `"soon"` or `"5 weeks"`.
+    Parse {
+        /// The text that didn't parse.
+        text: String,
+    },
+    /// The duration is negative, not a number, or too large for a `Duration`.
+    OutOfRange,
+}
+
+impl fmt::Display for DurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DurationError::Parse { text } => write!(
+                f,
+                "invalid duration '{text}', expected a number and an optional unit, for example, '250ms' or '1.5s'"
+            ),
+            DurationError::OutOfRange => write!(f, "duration is negative or out of range"),
+        }
+    }
+}
+
+impl std::error::Error for DurationError {}
+
+impl ToDuration for Duration {
+    fn to_duration(&self) -> Result<Duration, DurationError> {
+        Ok(*self)
+    }
+}
+
+impl ToDuration for u64 {
+    fn to_duration(&self) -> Result<Duration, DurationError> {
+        Ok(Duration::from_secs(*self))
+    }
+}
+
+impl ToDuration for u32 {
+    fn to_duration(&self) -> Result<Duration, DurationError> {
+        Ok(Duration::from_secs(u64::from(*self)))
+    }
+}
+
+// Unsuffixed integer literals, for example, the "5" of "timeout(5)", are i32.
+impl ToDuration for i32 {
+    fn to_duration(&self) -> Result<Duration, DurationError> {
+        i64::from(*self).to_duration()
+    }
+}
+
+impl ToDuration for i64 {
+    fn to_duration(&self) -> Result<Duration, DurationError> {
+        let secs = u64::try_from(*self).map_err(|_| DurationError::OutOfRange)?;
+        Ok(Duration::from_secs(secs))
+    }
+}
+
+impl ToDuration for f64 {
+    fn to_duration(&self) -> Result<Duration, DurationError> {
+        Duration::try_from_secs_f64(*self).map_err(|_| DurationError::OutOfRange)
+    }
+}
+
+impl ToDuration for f32 {
+    fn to_duration(&self) -> Result<Duration, DurationError> {
+        f64::from(*self).to_duration()
+    }
+}
+
+impl ToDuration for str {
+    fn to_duration(&self) -> Result<Duration, DurationError> {
+        let parse_error = || DurationError::Parse {
+            text: self.to_string(),
+        };
+        let text = self.trim();
+        let unit_start = text.find(|c: char| c.is_alphabetic()).unwrap_or(text.len());
+        let (number, unit) = text.split_at(unit_start);
+        let number = number.trim_end();
+        // The number of nanoseconds in one unit.
+        let unit_nanos: u64 = match unit {
+            "ns" => 1,
+            "us" | "µs" => 1_000,
+            "ms" => 1_000_000,
+            "" | "s" => 1_000_000_000,
+            "m" | "min" => 60_000_000_000,
+            "h" => 3_600_000_000_000,
+            _ => return Err(parse_error()),
+        };
+        // Whole numbers convert exactly. Others, for example, "1.5", go through f64.
+        if let Ok(count) = number.parse::<u64>() {
+            let nanos = u128::from(count) * u128::from(unit_nanos);
+            let secs =
+                u64::try_from(nanos / 1_000_000_000).map_err(|_| DurationError::OutOfRange)?;
+            Ok(Duration::new(secs, (nanos % 1_000_000_000) as u32))
+        } else {
+            let count = number.parse::<f64>().map_err(|_| parse_error())?;
+            (count * unit_nanos as f64 / 1e9).to_duration()
+        }
+    }
+}
+
+impl ToDuration for String {
+    fn to_duration(&self) -> Result<Duration, DurationError> {
+        self.as_str().to_duration()
+    }
+}
+
+impl<T: ToDuration + ?Sized> ToDuration for &T {
+    fn to_duration(&self) -> Result<Duration, DurationError> {
+        (**self).to_duration()
+    }
+}
@@ -1,8 +1,48 @@
 #![warn(missing_docs)]
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "anyhow")]
+mod anyhow_error;
+mod arg;
+mod borrow_iter;
+mod c_str;
+mod display;
+mod duration;
+mod lookup;
+mod markers;
+mod multi_iter;
+mod one_or_many;
+mod range;
+mod set;
+mod try_iter;
+
+#[cfg(feature = "anyhow")]
+pub use anyhow_error::IntoAnyhow;
+pub use arg::Arg;
+pub use borrow_iter::{BorrowIter, Borrowed};
+pub use c_str::ToCStr;
+pub use display::display_to_cow;
+pub use duration::{DurationError, ToDuration};
+pub use lookup::Lookup;
+#[cfg(feature = "anyhow")]
+pub use markers::AnyAnyhow;
+pub use markers::{
+    AnyArc, AnyArray, AnyArrayMut, AnyBytes, AnyCStr, AnyCow, AnyDisplay, AnyDuration, AnyError,
+    AnyInto, AnyIter, AnyIterBorrow, AnyIterMulti, AnyMap, AnyNdArray, AnyNdArrayMut, AnyOneOrMany,
+    AnyOption, AnyOsStr, AnyPath, AnyRange, AnySet, AnySocketAddr, AnyString, AnyStringMut,
+    AnyTryInto, AnyTryIter,
+};
+pub use multi_iter::MultiIter;
+pub use one_or_many::{Many, OneOrMany, Single};
+pub use range::{resolve_range, RangeError, RangeIndex};
+pub use set::{Contains, IntoSet, Set};
+pub use try_iter::{TryItem, TryIter};
+
 /// A macro for easier writing of functions that accept any string-, path-, iterator-, array-, or ndarray-like input.
-/// The AnyInputs are `AnyString`, `AnyPath`, `AnyIter`, `AnyArray`, and (optionally) `AnyNdArray`.
+/// The AnyInputs include `AnyString`, `AnyPath`, `AnyIter`, `AnyArray`, `AnyBytes`, `AnyOsStr`, `AnyCStr`,
+/// `AnyArrayMut`, `AnyStringMut`, `AnyCow`, `AnyInto`, `AnyTryInto`,
+/// `AnyOption`, `AnyMap`, `AnySet`, `AnyRange`,
+/// `AnyIterBorrow`, `AnyIterMulti`, `AnyTryIter`, `AnyOneOrMany`, `AnyDisplay`, `AnyDuration`, `AnyArc`, `AnyError`, `AnySocketAddr`, and (optionally) `AnyNdArray`, `AnyNdArrayMut`, and `AnyAnyhow`.
 ///
 /// See the [documentation](https://docs.rs/anyinput/) for for details.
 ///
@@ -0,0 +1,120 @@
+// Runtime support for AnyMap. An AnyMap input is an iterator of key-value pairs. Collecting it into a `Lookup`
+// allows looking up values by a borrowed key, whatever map-like thing the caller passed.
+
+use std::borrow::Borrow;
+
+/// Key-value pairs, collected from an `AnyMap` input, that can be looked up by a borrowed key.
+///
+/// With [`get`](Lookup::get), the keys need only be `AsRef` of the lookup type, so, for example,
+/// `AnyMap<AnyString, V>` keys can be looked up by `&str`. With [`get_borrow`](Lookup::get_borrow), plain keys,
+/// for example, the `usize` keys of `AnyMap<usize, V>`, are looked up through `Borrow`, as with a `HashMap`.
+/// As with collecting into a `HashMap`, when a key appears more than once, the last value wins.
+///
+/// Collecting moves the pairs into a `Vec` (or, for an input such as `&HashMap`, copies only references) and
+/// lookups search the pairs in order, which suits small maps such as settings or headers. For many keys of a
+/// hashable type, collect into a `HashMap` instead.
+///
+/// # Example
+/// ```
+/// use anyinput::{anyinput, Lookup};
+/// use std::collections::HashMap;
+///
+/// #[anyinput]
+/// fn verbosity(settings: AnyMap<AnyString, usize>) -> usize {
+///     let settings: Lookup<_, _> = settings.collect();
+///     settings.get("verbosity").copied().unwrap_or(0)
+/// }
+///
+/// assert_eq!(verbosity([("verbosity", 2)]), 2);
+/// assert_eq!(verbosity(vec![("verbosity".to_string(), 3)]), 3);
+/// assert_eq!(verbosity(HashMap::<String, usize>::new()), 0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Lookup<K, V> {
+    pairs: Vec<(K, V)>,
+}
+
+impl<K, V> Lookup<K, V> {
+    /// Returns the value of the key, if any.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: AsRef<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.pairs
+            .iter()
+            .rev()
+            .find(|(k, _)| k.as_ref() == key)
+            .map(|(_, v)| v)
+    }
+
+    /// Tells if the key is present.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: AsRef<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.get(key).is_some()
+    }
+
+    /// Returns the value of the key, if any, for keys that `Borrow` the lookup type, for example, `usize` keys
+    /// looked up by `&usize` or `String` keys looked up by `&str`.
+    ///
+    /// # Example
+    /// ```
+    /// use anyinput::{anyinput, Lookup};
+    /// use std::collections::BTreeMap;
+    ///
+    /// #[anyinput]
+    /// fn next_id(links: AnyMap<usize, usize>, id: usize) -> Option<usize> {
+    ///     let links: Lookup<_, _> = links.collect();
+    ///     links.get_borrow(&id).copied()
+    /// }
+    ///
+    /// assert_eq!(next_id([(1, 2), (2, 3)], 2), Some(3));
+    /// assert_eq!(next_id(BTreeMap::from([(1, 2)]), 1), Some(2));
+    /// ```
+    pub fn get_borrow<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.pairs
+            .iter()
+            .rev()
+            .find(|(k, _)| k.borrow() == key)
+            .map(|(_, v)| v)
+    }
+
+    /// Tells if the key is present, for keys that `Borrow` the lookup type.
+    pub fn contains_key_borrow<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.get_borrow(key).is_some()
+    }
+
+    /// Returns the number of pairs, including any with repeated keys.
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    /// Tells if there are no pairs.
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    /// Iterates over the pairs in their original order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.pairs.iter().map(|(k, v)| (k, v))
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for Lookup<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Lookup {
+            pairs: iter.into_iter().collect(),
+        }
+    }
+}
@@ -0,0 +1,660 @@
+// Marker types named after the AnyInputs. The `#[anyinput]` macro replaces these names before the compiler
+// sees them, so the types are never used or constructed. They exist so that rustdoc, IDE hover, go-to-definition,
+// and completion can explain each AnyInput.
+
+use std::convert::Infallible;
+use std::marker::PhantomData;
+
+/// Any string-like thing, for example, `&str`, `String`, or `&String`, borrowed or moved.
+///
+/// The `#[anyinput]` macro replaces `AnyString` with a generic bounded by `AsRef<str>`.
+/// At the top level, it converts the input into a `&str`. When nested, use `.as_ref()`.
+/// An optional generic parameter adds bounds, for example, `AnyString<Clone + Send>`.
+///
+/// # Example
+/// ```
+/// use anyinput::anyinput;
+///
+/// #[anyinput]
+/// fn len_plus_2(s: AnyString) -> usize {
+///     s.len() + 2
+/// }
+///
+/// assert_eq!(len_plus_2("Hello"), 7);
+/// assert_eq!(len_plus_2("Hello".to_string()), 7);
+/// ```
+pub struct AnyString<Bounds: ?Sized = ()>(Infallible, PhantomData<Bounds>);
+
+/// Any path-like or string-like thing, for example, `&Path`, `PathBuf`, `&str`, or `String`, borrowed or moved.
+///
+/// The `#[anyinput]` macro replaces `AnyPath` with a generic bounded by `AsRef<std::path::Path>`.
+/// At the top level, it converts the input into a `&Path`. When nested, use `.as_ref()`.
+/// An optional generic parameter adds bounds, for example, `AnyPath<Clone + Send>`.
+///
+/// # Example
+/// ```
+/// use anyinput::anyinput;
+/// use std::path::PathBuf;
+///
+/// #[anyinput]
+/// fn component_count(path: AnyPath) -> usize {
+///     path.iter().count()
+/// }
+///
+/// assert_eq!(component_count("usr/files/home"), 3);
+/// assert_eq!(component_count(PathBuf::from("usr/files")), 2);
+/// ```
+pub struct AnyPath<Bounds: ?Sized = ()>(Infallible, PhantomData<Bounds>);
+
+/// Any iterator-like thing of `T`, for example, a `Vec<T>`, an array, a range, or an iterator.
+///
+/// The `#[anyinput]` macro replaces `AnyIter<T>` with a generic bounded by `IntoIterator<Item = T>`.
+/// At the top level, it converts the input into `<I as IntoIterator>::IntoIter`. When nested, use `.into_iter()`.
+/// An optional second generic parameter bounds the iterator, for example, `AnyIter<T, ExactSizeIterator>`.
+///
+/// # Example
+/// ```
+/// use anyinput::anyinput;
+///
+/// #[anyinput]
+/// fn total_len(iter: AnyIter<AnyString>) -> usize {
+///     iter.map(|s| s.as_ref().len()).sum()
+/// }
+///
+/// assert_eq!(total_len(["a", "bb", "ccc"]), 6);
+/// assert_eq!(total_len(vec!["a".to_string()]), 1);
+/// ```
+pub struct AnyIter<T: ?Sized, Bounds: ?Sized = ()>(Infallible, PhantomData<T>, PhantomData<Bounds>);
+
+/// Any array-like thing of `T`, for example, a `Vec<T>`, an array, or a slice, borrowed or moved.
+///
+/// The `#[anyinput]` macro replaces `AnyArray<T>` with a generic bounded by `AsRef<[T]>`.
+/// At the top level, it converts the input into a `&[T]`. When nested, use `.as_ref()`.
+/// An optional second generic parameter adds bounds, for example, `AnyArray<T, Clone>`.
+///
+/// # Example
+/// ```
+/// use anyinput::anyinput;
+///
+/// #[anyinput]
+/// fn first_or_zero(array: AnyArray<usize>) -> usize {
+///     array.first().copied().unwrap_or(0)
+/// }
+///
+/// assert_eq!(first_or_zero([5, 6, 7]), 5);
+/// assert_eq!(first_or_zero(vec![]), 0);
+/// ```
+pub struct AnyArray<T: ?Sized, Bounds: ?Sized = ()>(
+    Infallible,
+    PhantomData<T>,
+    PhantomData<Bounds>,
+);
+
+/// Any 1-D array-like thing of `T`, for example, a `&Vec<T>`, a `&[T]`, or a `&ndarray::Array1<T>`. It must be borrowed.
+///
+/// The `#[anyinput]` macro replaces `AnyNdArray<T>` with a generic bounded by `Into<ndarray::ArrayView1<T>>`.
+/// At the top level, it converts the input into an `ndarray::ArrayView1<T>`. When nested, use `.into()`.
+/// Requires the `ndarray` feature.
+pub struct AnyNdArray<T: ?Sized, Bounds: ?Sized = ()>(
+    Infallible,
+    PhantomData<T>,
+    PhantomData<Bounds>,
+);
+
+/// Any mutable array-like thing of `T`, for example, a `&mut Vec<T>`, a `&mut [T; N]`, or a `&mut [T]`.
+///
+/// The `#[anyinput]` macro replaces `AnyArrayMut<T>` with a generic bounded by `AsMut<[T]>`.
+/// At the top level, it converts the input into a `&mut [T]`. When nested, make the binding `mut` and use `.as_mut()`.
+/// An optional second generic parameter adds bounds, for example, `AnyArrayMut<T, Send>`.
+///
+/// # Example
+/// ```
+/// use anyinput::anyinput;
+///
+/// #[anyinput]
+/// fn fill(array: AnyArrayMut<usize>, value: usize) {
+///     array.fill(value);
+/// }
+///
+/// let mut vec = vec![1, 2, 3];
+/// fill(&mut vec, 0);
+/// assert_eq!(vec, [0, 0, 0]);
+/// let mut array = [1, 2];
+/// fill(&mut array, 5);
+/// assert_eq!(array, [5, 5]);
+/// ```
+pub struct AnyArrayMut<T: ?Sized, Bounds: ?Sized = ()>(
+    Infallible,
+    PhantomData<T>,
+    PhantomData<Bounds>,
+);
+
+/// Any mutable string-like thing, for example, a `&mut String` or a `&mut str`.
+///
+/// The `#[anyinput]` macro replaces `AnyStringMut` with a generic bounded by `AsMut<str>`.
+/// At the top level, it converts the input into a `&mut str`. When nested, make the binding `mut` and use `.as_mut()`.
+///
+/// # Example
+/// ```
+/// use anyinput::anyinput;
+///
+/// #[anyinput]
+/// fn shout(s: AnyStringMut) {
+///     s.make_ascii_uppercase();
+/// }
+///
+/// let mut string = "hello".to_string();
+/// shout(&mut string);
+/// assert_eq!(string, "HELLO");
+/// ```
+pub struct AnyStringMut<Bounds: ?Sized = ()>(Infallible, PhantomData<Bounds>);
+
+/// Any mutable 1-D array-like thing of `T`, for example, a `&mut Vec<T>`, a `&mut [T]`, or a `&mut ndarray::Array1<T>`.
+///
+/// The `#[anyinput]` macro replaces `AnyNdArrayMut<T>` with a generic bounded by `Into<ndarray::ArrayViewMut1<T>>`.
+/// At the top level, it converts the input into an `ndarray::ArrayViewMut1<T>`. When nested, use `.into()`.
+/// Requires the `ndarray` feature.
+pub struct AnyNdArrayMut<T: ?Sized, Bounds: ?Sized = ()>(
+    Infallible,
+    PhantomData<T>,
+    PhantomData<Bounds>,
+);
+
+/// Any byte-oriented thing, for example, `Vec<u8>`, `&[u8]`, `&[u8; N]`, `&str`, or `String`, borrowed or moved.
+///
+/// The `#[anyinput]` macro replaces `AnyBytes` with a generic bounded by `AsRef<[u8]>`.
+/// At the top level, it converts the input into a `&[u8]`. When nested, use `.as_ref()`.
+/// An optional generic parameter adds bounds, for example, `AnyBytes<Clone + Send>`.
+///
+/// # Example
+/// ```
+/// use anyinput::anyinput;
+///
+/// #[anyinput]
+/// fn checksum(bytes: AnyBytes) -> u32 {
+///     bytes.iter().map(|&b| b as u32).sum()
+/// }
+///
+/// assert_eq!(checksum(vec![1u8, 2, 3]), 6);
+/// assert_eq!(checksum("ab"), 97 + 98);
+/// ```
+pub struct AnyBytes<Bounds: ?Sized = ()>(Infallible, PhantomData<Bounds>);
+
+/// Any OS-string-like thing, for example, `&OsStr`, `OsString`, `&str`, `String`, or `&Path`, borrowed or moved.
+///
+/// The `#[anyinput]` macro replaces `AnyOsStr` with a generic bounded by `AsRef<std::ffi::OsStr>`.
+/// At the top level, it converts the input into a `&OsStr`. When nested, use `.as_ref()`.
+/// Unlike `AnyString`, it accepts text that is not UTF-8, for example, the items of `std::env::args_os()`.
+///
+/// # Example
+/// ```
+/// use anyinput::anyinput;
+/// use std::process::Command;
+///
+/// #[anyinput]
+/// fn arg_count(program: AnyOsStr, args: AnyIter<AnyOsStr>) -> usize {
+///     let mut command = Command::new(program);
+///     command.args(args);
+///     command.get_args().count()
+/// }
+///
+/// assert_eq!(arg_count("ls", std::env::args_os().take(1)), 1);
+/// assert_eq!(arg_count("ls", vec!["-l".to_string(), "-a".to_string()]), 2);
+/// ```
+pub struct AnyOsStr<Bounds: ?Sized = ()>(Infallible, PhantomData<Bounds>);
+
+/// Any C-string-like or string-like thing, for example, `&CStr`, `CString`, `&str`, or `String`, borrowed or moved.
+///
+/// The `#[anyinput]` macro replaces `AnyCStr` with a generic bounded by [`ToCStr`](crate::ToCStr).
+/// At the top level, it converts the input into a `Cow<CStr>` with `.to_c_str()?`, so the function must
+/// return a `Result` whose error type accepts a `std::ffi::NulError`. When nested, use `.to_c_str()?`.
+/// Text with an interior NUL byte is the only way the conversion fails.
+///
+/// # Example
+/// ```
+/// use anyinput::anyinput;
+/// use std::ffi::{CString, NulError};
+///
+/// #[anyinput]
+/// fn c_len(s: AnyCStr) -> Result<usize, NulError> {
+///     Ok(s.to_bytes().len())
+/// }
+///
+/// assert_eq!(c_len(c"Hello")?, 5);
+/// assert_eq!(c_len(CString::new("Hello")?)?, 5);
+/// assert_eq!(c_len("Hello")?, 5);
+/// assert!(c_len("Hel\0lo").is_err());
+/// # Ok::<(), NulError>(())
+/// ```
+pub struct AnyCStr<Bounds: ?Sized = ()>(Infallible, PhantomData<Bounds>);
+
+/// Any borrowed or owned thing that converts into a `Cow<T>`, for example, for `AnyCow<str>`, a `&str`, a `String`,
+/// or a `&String`. `T` is usually `str`, `Path`, or `[T]`.
+///
+/// The `#[anyinput]` macro replaces `AnyCow<T>` with a generic bounded by `Into<Cow<'a, T>>`, where `'a` is a
+/// generated lifetime. At the top level, it converts the input into a `Cow<T>`, which borrows a borrowed input and
+/// keeps an owned input without copying it. When nested, use `.into()`.
+/// An optional second generic parameter adds bounds, for example, `AnyCow<str, Send>`.
+///
+/// # Example
+/// ```
+/// use anyinput::anyinput;
+/// use std::borrow::Cow;
+///
+/// #[anyinput]
+/// fn cache(s: AnyCow<str>) -> Cow<'static, str> {
+///     Cow::Owned(s.into_owned()) // into_owned doesn't copy an owned String.
+/// }
+///
+/// assert_eq!(cache("hello"), "hello");
+/// assert_eq!(cache("hello".to_string()), "hello");
+/// ```
+pub struct AnyCow<T: ?Sized, Bounds: ?Sized = ()>(Infallible, PhantomData<T>, PhantomData<Bounds>);
+
+/// Anything that converts into `T`, for example, for `AnyInto<Config>`, anything with `impl From<_> for Config`.
+///
+/// The `#[anyinput]` macro replaces `AnyInto<T>` with a generic bounded by `Into<T>`.
+/// At the top level, it converts the input into a `T`. When nested, use `.into()`.
+/// An optional second generic parameter adds bounds, for example, `AnyInto<T, Send>`.
+///
+/// # Example
+/// ```
+/// use anyinput::anyinput;
+///
+/// #[anyinput]
+/// fn double(x: AnyInto<u64>) -> u64 {
+///     x * 2
+/// }
+///
+/// assert_eq!(double(3u8), 6);
+/// assert_eq!(double(3u32), 6);
+/// ```
+pub struct AnyInto<T: ?Sized, Bounds: ?Sized = ()>(Infallible, PhantomData<T>, PhantomData<Bounds>);
+
+/// Anything that may convert into `T`, for example, for `AnyTryInto<u16>`, any integer.
+///
+/// The `#[anyinput]` macro replaces `AnyTryInto<T>` with a generic bounded by `TryInto<T>`. It also requires the
+/// conversion's error to convert into the function's error type, so the function must return a `Result<_, E>`
+/// that names `E`. At the top level, it converts the input into a `T` with `.try_into()` and returns any error.
+/// When nested, use `.try_into().map_err(Into::<E>::into)?`.
+///
+/// # Example
+/// ```
+/// use anyinput::anyinput;
+///
+/// #[anyinput]
+/// fn port(x: AnyTryInto<u16>) -> Result<u16, Box<dyn std::error::Error>> {
+///     Ok(x)
+/// }
+///
+/// assert_eq!(port(8080u32)?, 8080);
+/// assert!(port(-1i32).is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct AnyTryInto<T: ?Sized, Bounds: ?Sized = ()>(
+    Infallible,
+    PhantomData<T>,
+    PhantomData<Bounds>,
+);
+
+/// Anything that converts into an `Option<T>`, for example, for `AnyOption<usize>`, `5`, `Some(5)`, or `None`.
+///
+/// The `#[anyinput]` macro replaces `AnyOption<T>` with a generic bounded by `Into<Option<T>>`.
+/// At the top level, it converts the input into an `Option<T>`. When nested, use `.into()`.
+/// An optional second generic parameter adds bounds, for example, `AnyOption<T, Send>`.
+///
+/// When `T` is itself an AnyInput, for example, `AnyOption<AnyString>`, a caller passing `Some(..)` or `None`
+/// must name the nested type, for example, `greeting::<&str, _>(None)`.
+///
+/// # Example
+/// ```
+/// use anyinput::anyinput;
+///
+/// #[anyinput]
+/// fn row_count(max_rows: AnyOption<usize>) -> usize {
+///     max_rows.unwrap_or(100)
+/// }
+///
+/// assert_eq!(row_count(5), 5);
+/// assert_eq!(row_count(None), 100);
+/// ```
+pub struct AnyOption<T: ?Sized, Bounds: ?Sized = ()>(
+    Infallible,
+    PhantomData<T>,
+    PhantomData<Bounds>,
+);
+
+/// Any map-like thing from `K` to `V`, for example, a `HashMap<K, V>`, a `BTreeMap<K, V>`, a `Vec<(K, V)>`,
+/// or an array of `(K, V)`.
+///
+/// The `#[anyinput]` macro replaces `AnyMap<K, V>` with a generic bounded by `IntoIterator<Item = (K, V)>`.
+/// At the top level, it converts the input into an iterator of `(K, V)`. When nested, use `.into_iter()`.
+/// To look up values by a borrowed key, collect the pairs into a [`Lookup`](crate::Lookup).
+/// An optional third generic parameter bounds the iterator, for example, `AnyMap<K, V, ExactSizeIterator>`.
+///
+/// # Example
+/// ```
+/// use anyinput::anyinput;
+/// use std::collections::BTreeMap;
+///
+/// #[anyinput]
+/// fn total_len(headers: AnyMap<AnyString, AnyString>) -> usize {
+///     headers.map(|(k, v)| k.as_ref().len() + v.as_ref().len()).sum()
+/// }
+///
+/// assert_eq!(total_len([("a", "bc")]), 3);
+/// assert_eq!(total_len(BTreeMap::from([("a".to_string(), "bc")])), 3);
+/// ```
+pub struct AnyMap<K: ?Sized, V: ?Sized, Bounds: ?Sized = ()>(
+    Infallible,
+    PhantomData<K>,
+    PhantomData<V>,
+    PhantomData<Bounds>,
+);
+
+/// Any set-like or array-like thing of `T`, for example, a `HashSet<T>`, a `BTreeSet<T>`, a `Vec<T>`, a slice, or
+/// a range, borrowed or moved.
+///
+/// The `#[anyinput]` macro replaces `AnySet<T>` with a generic bounded by [`IntoSet<T>`](crate::IntoSet).
+/// At the top level, it converts the input into a [`Set`](crate::Set), whose `contains` method tells if a value
+/// is present. Sets, ranges, `Vec`s, arrays, and slices do their own lookup. An iterator is collected into a `HashSet`.
+/// When nested, use `.into_set()`.
+/// An optional second generic parameter adds bounds, for example, `AnySet<T, Send>`.
+///
+/// # Example
+/// ```
+/// use anyinput::anyinput;
+/// use std::collections::HashSet;
+///
+/// #[anyinput]
+/// fn keep(ids: AnySet<u32>, values: AnyArray<u32>) -> Vec<u32> {
+///     values.iter().copied().filter(|id| ids.contains(id)).collect()
+/// }
+///
+/// assert_eq!(keep(HashSet::from([1, 3]), [1, 2, 3]), [1, 3]);
+/// assert_eq!(keep(vec![2], [1, 2, 3]), [2]);
+/// assert_eq!(keep(2..10, [1, 2, 3]), [2, 3]);
+/// ```
+pub struct AnySet<T: ?Sized, Bounds: ?Sized = ()>(Infallible, PhantomData<T>, PhantomData<Bounds>);
+
+/// Any range of `T`, for example, `2..5`, `..5`, `2..=4`, `2..`, or `..`. `T` defaults to `usize`.
+///
+/// The `#[anyinput]` macro replaces `AnyRange` with a generic bounded by `RangeBounds<usize>`, and `AnyRange<T>`
+/// with one bounded by `RangeBounds<T>`. At the top level, it converts the input into a `Range<T>` within `0..len`,
+/// where the function's attribute gives `len`, for example, `#[anyinput(len = data.len())]`. The conversion comes
+/// after the other inputs' conversions, so `len` may use them. It returns a [`RangeError`](crate::RangeError) with
+/// '?' if the range doesn't fit, so the function must return a `Result`. When nested, use
+/// [`resolve_range`](crate::resolve_range).
+///
+/// # Example
+/// ```
+/// use anyinput::{anyinput, RangeError};
+///
+/// #[anyinput(len = data.len())]
+/// fn sum(data: AnyArray<u32>, range: AnyRange) -> Result<u32, RangeError> {
+///     Ok(data[range].iter().sum())
+/// }
+///
+/// assert_eq!(sum([1, 2, 3, 4], 1..3)?, 5);
+/// assert_eq!(sum([1, 2, 3, 4], ..)?, 10);
+/// assert!(sum([1, 2, 3, 4], 3..9).is_err());
+/// # Ok::<(), RangeError>(())
+/// ```
+pub struct AnyRange<T: ?Sized = usize, Bounds: ?Sized = ()>(
+    Infallible,
+    PhantomData<T>,
+    PhantomData<Bounds>,
+);
+
+/// Any iterator-like thing of `T` or `&T`, for example, a `Vec<T>`, a `&Vec<T>`, `vec.iter()`, or a range.
+///
+/// The `#[anyinput]` macro replaces `AnyIterBorrow<T>` with a generic bounded by `IntoIterator` whose items are `Borrow<T>`.
+/// At the top level, it converts the input into a [`BorrowIter`](crate::BorrowIter), whose items dereference
+/// to `&T`. When nested, use `anyinput::BorrowIter::<_, T>::new(..)`.
+/// An optional second generic parameter bounds the iterator, for example, `AnyIterBorrow<T, ExactSizeIterator>`.
+///
+/// # Example
+/// ```
+/// use anyinput::anyinput;
+///
+/// #[anyinput]
+/// fn total(iter: AnyIterBorrow<usize>) -> usize {
+///     iter.map(|x| *x).sum()
+/// }
+///
+/// let vec = vec![1, 2, 3];
+/// assert_eq!(total(&vec), 6);
+/// assert_eq!(total(vec.iter()), 6);
+/// assert_eq!(total(1..4), 6);
+/// ```
+pub struct AnyIterBorrow<T: ?Sized, Bounds: ?Sized = ()>(
+    Infallible,
+    PhantomData<T>,
+    PhantomData<Bounds>,
+);
+
+/// Any iterator-like thing of `T` that can be iterated more than once, for example, a `&Vec<T>`, a slice, a range,
+/// or any other input whose iterator can be cloned.
+///
+/// The `#[anyinput]` macro replaces `AnyIterMulti<T>` with a generic bounded by
+/// `IntoIterator` whose items are `Borrow<T>` and whose iterator is `Clone`. At the top level, it converts the input
+/// into a [`MultiIter`](crate::MultiIter), whose `iter()` method starts a new pass with items that dereference to
+/// `&T`. When nested, use `anyinput::MultiIter::<_, T>::new(..)`. For borrowed inputs and ranges, a new pass
+/// doesn't allocate.
+/// An optional second generic parameter bounds the iterator, for example, `AnyIterMulti<T, ExactSizeIterator>`.
+///
+/// # Example
+/// ```
+/// use anyinput::anyinput;
+///
+/// #[anyinput]
+/// fn variance(data: AnyIterMulti<f64>) -> f64 {
+///     let len = data.iter().count() as f64;
+///     let mean = data.iter().map(|x| *x).sum::<f64>() / len;
+///     data.iter().map(|x| (*x - mean) * (*x - mean)).sum::<f64>() / len
+/// }
+///
+/// assert_eq!(variance([1.0, 3.0]), 1.0);
+/// let vec = vec![2.0, 4.0];
+/// assert_eq!(variance(&vec), 1.0);
+/// ```
+pub struct AnyIterMulti<T: ?Sized, Bounds: ?Sized = ()>(
+    Infallible,
+    PhantomData<T>,
+    PhantomData<Bounds>,
+);
+
+/// Any iterator-like thing of `T` or of `Result<T, E>`, for example, a `Vec<T>`, a range, or the lines of a reader.
+///
+/// The `#[anyinput]` macro replaces `AnyTryIter<T, E>` with a generic bounded by
+/// `IntoIterator` whose items are `TryItem<T, E>`. At the top level, it converts the input into a [`TryIter`](crate::TryIter),
+/// whose items are `Result<T, E>`, so the function body can use '?' on every item. When nested, use
+/// `anyinput::TryIter::<_, T, E>::new(..)`.
+/// An optional third generic parameter bounds the iterator, for example, `AnyTryIter<T, E, ExactSizeIterator>`.
+///
+/// When `T` is itself an AnyInput, for example, `AnyTryIter<AnyString, E>`, a caller passing `Result` items
+/// must name the nested type, for example, `total_len::<String, _>(reader.lines())`.
+///
+/// # Example
+/// ```
+/// use anyinput::anyinput;
+/// use std::io::{self, BufRead};
+///
+/// #[anyinput]
+/// fn total_len(lines: AnyTryIter<String, io::Error>) -> Result<usize, io::Error> {
+///     let mut total = 0;
+///     for line in lines {
+///         total += line?.len();
+///     }
+///     Ok(total)
+/// }
+///
+/// assert_eq!(total_len(io::Cursor::new("a\nbc\n").lines())?, 3);
+/// assert_eq!(total_len(vec!["a".to_string(), "bc".to_string()])?, 3);
+/// # Ok::<(), io::Error>(())
+/// ```
+pub struct AnyTryIter<T: ?Sized, E: ?Sized, Bounds: ?Sized = ()>(
+    Infallible,
+    PhantomData<T>,
+    PhantomData<E>,
+    PhantomData<Bounds>,
+);
+
+/// Either a single value of `T` or a collection of them, for example, for `AnyOneOrMany<AnyPath>`, `"a.bed"`,
+/// `["a.bed", "b.bed"]`, or a `&Vec<PathBuf>`.
+///
+/// The `#[anyinput]` macro replaces `AnyOneOrMany<T>` with a generic bounded by [`OneOrMany<T>`](crate::OneOrMany).
+/// At the top level, it converts the input into an iterator of `T`. When nested, use `.into_many()`.
+/// Single values are `&str`, `String`, `&Path`, `PathBuf`, and references to them, plus any type that implements
+/// [`Single`](crate::Single). To pass any other iterator-like thing, wrap it in [`Many`](crate::Many).
+/// An optional second generic parameter adds bounds, for example, `AnyOneOrMany<T, Send>`.
+///
+/// # Example
+/// ```
+/// use anyinput::anyinput;
+/// use std::path::PathBuf;
+///
+/// #[anyinput]
+/// fn count_beds(paths: AnyOneOrMany<AnyPath>) -> usize {
+///     paths.filter(|path| path.as_ref().extension() == Some("bed".as_ref())).count()
+/// }
+///
+/// assert_eq!(count_beds("a.bed"), 1);
+/// assert_eq!(count_beds(["a.bed", "b.txt"]), 1);
+/// assert_eq!(count_beds(&vec![PathBuf::from("a.bed")]), 1);
+/// ```
+pub struct AnyOneOrMany<T: ?Sized, Bounds: ?Sized = ()>(
+    Infallible,
+    PhantomData<T>,
+    PhantomData<Bounds>,
+);
+
+/// Anything that implements `Display`, for example, a string, a number, a `char`, or an enum.
+///
+/// The `#[anyinput]` macro replaces `AnyDisplay` with a generic bounded by `Display`.
+/// At the top level, it converts the input into a `Cow<str>`, borrowing, without allocating, if the input is a
+/// `str`, `String`, or `Cow<str>`. When nested, use [`anyinput::display_to_cow(&..)`](crate::display_to_cow).
+/// An optional generic parameter adds bounds, for example, `AnyDisplay<Clone + Send>`.
+///
+/// # Example
+/// ```
+/// use anyinput::anyinput;
+///
+/// #[anyinput]
+/// fn label(name: AnyDisplay, value: AnyDisplay) -> String {
+///     format!("{name}={value}")
+/// }
+///
+/// assert_eq!(label("width", 5), "width=5");
+/// assert_eq!(label('x', "wide".to_string()), "x=wide");
+/// ```
+pub struct AnyDisplay<Bounds: ?Sized = ()>(Infallible, PhantomData<Bounds>);
+
+/// Any duration-like thing, for example, a `Duration`, seconds as a `u64` or `f64`, or a string such as `"250ms"`.
+///
+/// The `#[anyinput]` macro replaces `AnyDuration` with a generic bounded by [`ToDuration`](crate::ToDuration).
+/// At the top level, it converts the input into a `Duration`. Because a string may not parse, it returns a
+/// [`DurationError`](crate::DurationError) with '?', so the function must return a `Result`. When nested, use
+/// `.to_duration()?`.
+/// An optional generic parameter adds bounds, for example, `AnyDuration<Clone + Send>`.
+///
+/// # Example
+/// ```
+/// use anyinput::{anyinput, DurationError};
+/// use std::time::Duration;
+///
+/// #[anyinput]
+/// fn timeout_ms(timeout: AnyDuration) -> Result<u128, DurationError> {
+///     Ok(timeout.as_millis())
+/// }
+///
+/// assert_eq!(timeout_ms(Duration::from_secs(2))?, 2000);
+/// assert_eq!(timeout_ms(2)?, 2000);
+/// assert_eq!(timeout_ms("250ms")?, 250);
+/// assert!(timeout_ms("soon").is_err());
+/// # Ok::<(), DurationError>(())
+/// ```
+pub struct AnyDuration<Bounds: ?Sized = ()>(Infallible, PhantomData<Bounds>);
+
+/// Anything that converts into an `Arc<T>`, for example, for `AnyArc<str>`, a `&str`, a `String`, a `Box<str>`,
+/// or an `Arc<str>`. `AnyArc<Path>` and `AnyArc<[T]>` work, too.
+///
+/// The `#[anyinput]` macro replaces `AnyArc<T>` with a generic bounded by `Into<Arc<T>>`.
+/// At the top level, it converts the input into an `Arc<T>`. An `Arc<T>` input is used as is, without copying.
+/// When nested, use `.into()`.
+/// An optional second generic parameter adds bounds, for example, `AnyArc<str, Send>`.
+///
+/// # Example
+/// ```
+/// use anyinput::anyinput;
+/// use std::sync::Arc;
+///
+/// #[anyinput]
+/// fn keep(names: &mut Vec<Arc<str>>, name: AnyArc<str>) {
+///     names.push(name);
+/// }
+///
+/// let mut names = Vec::new();
+/// keep(&mut names, "a");
+/// keep(&mut names, "b".to_string());
+/// let shared: Arc<str> = Arc::from("c");
+/// keep(&mut names, shared.clone());
+/// assert!(Arc::ptr_eq(&names[2], &shared));
+/// ```
+pub struct AnyArc<T: ?Sized, Bounds: ?Sized = ()>(Infallible, PhantomData<T>, PhantomData<Bounds>);
+
+/// Any error value, for example, an `io::Error`, a `ParseIntError`, a `String` message, or a boxed error.
+///
+/// The `#[anyinput]` macro replaces `AnyError` with a generic bounded by
+/// `Into<Box<dyn Error + Send + Sync>>`. At the top level, it converts the input into a
+/// `Box<dyn Error + Send + Sync>`. When nested, use `.into()`.
+/// An optional generic parameter adds bounds, for example, `AnyError<'static + Send>`.
+///
+/// # Example
+/// ```
+/// use anyinput::anyinput;
+///
+/// #[anyinput]
+/// fn describe(error: AnyError) -> String {
+///     format!("failed: {error}")
+/// }
+///
+/// assert_eq!(describe("bad input"), "failed: bad input");
+/// assert_eq!(describe("x".parse::<u8>().unwrap_err()), "failed: invalid digit found in string");
+/// ```
+pub struct AnyError<Bounds: ?Sized = ()>(Infallible, PhantomData<Bounds>);
+
+/// Any error value that [`AnyError`] accepts, converted into an `anyhow::Error`.
+///
+/// The `#[anyinput]` macro replaces `AnyAnyhow` with a generic bounded by `IntoAnyhow`.
+/// At the top level, it converts the input into an `anyhow::Error`. When nested, use `.into_anyhow()`.
+/// Requires the `anyhow` feature.
+#[cfg(feature = "anyhow")]
+pub struct AnyAnyhow<Bounds: ?Sized = ()>(Infallible, PhantomData<Bounds>);
+
+/// Any socket-address-like thing, for example, `"127.0.0.1:8080"`, `("localhost", 8080)`, or a `SocketAddr`.
+///
+/// The `#[anyinput]` macro replaces `AnySocketAddr` with a generic bounded by `ToSocketAddrs`.
+/// At the top level, it resolves the input into a `Vec<SocketAddr>`, for example, with both the IPv4 and the IPv6
+/// address of a host name. Because resolving may fail, it returns an `io::Error` with '?', so the function must
+/// return a `Result`. When nested, use `.to_socket_addrs()?`.
+/// An optional generic parameter adds bounds, for example, `AnySocketAddr<Clone + Send>`.
+///
+/// # Example
+/// ```
+/// use anyinput::anyinput;
+/// use std::io;
+/// use std::net::SocketAddr;
+///
+/// #[anyinput]
+/// fn first_port(addr: AnySocketAddr) -> Result<u16, io::Error> {
+///     Ok(addr[0].port())
+/// }
+///
+/// assert_eq!(first_port("127.0.0.1:8080")?, 8080);
+/// assert_eq!(first_port(("127.0.0.1", 8081))?, 8081);
+/// assert_eq!(first_port(SocketAddr::from(([127, 0, 0, 1], 8082)))?, 8082);
+/// assert!(first_port("127.0.0.1").is_err());
+/// # Ok::<(), io::Error>(())
+/// ```
+pub struct AnySocketAddr<Bounds: ?Sized = ()>(Infallible, PhantomData<Bounds>);
@@ -0,0 +1,87 @@
+// Runtime support for AnyIterMulti. The `#[anyinput]` macro converts AnyIterMulti inputs into a `MultiIter`,
+// which starts a new pass by cloning the input's iterator.
+
+use crate::{BorrowIter, Borrowed};
+use std::borrow::Borrow;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// An iterator-like value that can be iterated more than once, for example, for a mean and then a variance.
+///
+/// Each pass clones the iterator made from the input. For borrowed inputs, such as a `&Vec<T>` or a slice, and for
+/// ranges, that clone is cheap and doesn't allocate. As with [`BorrowIter`], the input's items may be `T`, `&T`, or
+/// anything else that borrows as `T`, and each pass yields items that dereference to `&T`.
+///
+/// # Example
+/// ```
+/// use anyinput::MultiIter;
+///
+/// let data = MultiIter::<_, i32>::new(1..4);
+/// assert_eq!(data.iter().map(|x| *x).sum::<i32>(), 6);
+/// assert_eq!(data.iter().count(), 3);
+/// let vec = vec![1, 2, 3];
+/// for x in &MultiIter::<_, i32>::new(&vec) {
+///     assert!(*x < 4);
+/// }
+/// ```
+pub struct MultiIter<I, T: ?Sized> {
+    iter: I,
+    phantom: PhantomData<fn(&T)>,
+}
+
+impl<I: Iterator + Clone, T: ?Sized> MultiIter<I, T>
+where
+    I::Item: Borrow<T>,
+{
+    /// Creates the value from anything iterator-like whose iterator can be cloned.
+    pub fn new(into_iter: impl IntoIterator<IntoIter = I>) -> Self {
+        MultiIter {
+            iter: into_iter.into_iter(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Starts a new pass.
+    pub fn iter(&self) -> BorrowIter<I, T> {
+        BorrowIter::new(self.iter.clone())
+    }
+}
+
+impl<I: Iterator + Clone, T: ?Sized> IntoIterator for &MultiIter<I, T>
+where
+    I::Item: Borrow<T>,
+{
+    type Item = Borrowed<I::Item, T>;
+    type IntoIter = BorrowIter<I, T>;
+
+    fn into_iter(self) -> BorrowIter<I, T> {
+        self.iter()
+    }
+}
+
+impl<I: Iterator, T: ?Sized> IntoIterator for MultiIter<I, T>
+where
+    I::Item: Borrow<T>,
+{
+    type Item = Borrowed<I::Item, T>;
+    type IntoIter = BorrowIter<I, T>;
+
+    fn into_iter(self) -> BorrowIter<I, T> {
+        BorrowIter::new(self.iter)
+    }
+}
+
+impl<I: Clone, T: ?Sized> Clone for MultiIter<I, T> {
+    fn clone(&self) -> Self {
+        MultiIter {
+            iter: self.iter.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I: fmt::Debug, T: ?Sized> fmt::Debug for MultiIter<I, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MultiIter").field(&self.iter).finish()
+    }
+}
@@ -0,0 +1,120 @@
+// Runtime support for AnyOneOrMany. The `#[anyinput]` macro bounds AnyOneOrMany inputs by `OneOrMany` and converts
+// them with `.into_many()`. A single value becomes an iterator of one. A `Vec`, array, or slice of single values,
+// borrowed or moved, becomes an iterator of its items.
+
+use std::iter::{self, Once};
+use std::path::{Path, PathBuf};
+use std::slice;
+use std::vec;
+
+/// A value that an `AnyOneOrMany` input accepts by itself, for example, `"a.bed"` rather than `["a.bed"]`.
+///
+/// Implement it for your own type to accept that type, or collections of it, as an `AnyOneOrMany`.
+pub trait Single {}
+
+impl Single for &str {}
+impl Single for String {}
+impl Single for &Path {}
+impl Single for PathBuf {}
+impl<T: Single> Single for &T {}
+
+/// Converts a single value, or a collection of values, into an iterator of values.
+///
+/// Any [`Single`] value converts into an iterator of one. `Vec`s and arrays of single values convert into
+/// iterators of their items. Borrowed `Vec`s, arrays, and slices convert into iterators of references. To accept
+/// any other iterator-like thing, wrap it in [`Many`].
+///
+/// # Example
+/// ```
+/// use anyinput::OneOrMany;
+///
+/// assert_eq!("a.bed".into_many().collect::<Vec<_>>(), ["a.bed"]);
+/// assert_eq!(["a.bed", "b.bed"].into_many().collect::<Vec<_>>(), ["a.bed", "b.bed"]);
+/// ```
+pub trait OneOrMany<T> {
+    /// The iterator that the value converts into.
+    type IntoIter: Iterator<Item = T>;
+
+    /// Converts into an iterator of values.
+    fn into_many(self) -> Self::IntoIter;
+}
+
+impl<T: Single> OneOrMany<T> for T {
+    type IntoIter = Once<T>;
+
+    fn into_many(self) -> Self::IntoIter {
+        iter::once(self)
+    }
+}
+
+impl<T: Single> OneOrMany<T> for Vec<T> {
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_many(self) -> Self::IntoIter {
+        self.into_iter()
+    }
+}
+
+impl<T: Single, const N: usize> OneOrMany<T> for [T; N] {
+    type IntoIter = std::array::IntoIter<T, N>;
+
+    fn into_many(self) -> Self::IntoIter {
+        self.into_iter()
+    }
+}
+
+impl<'a, T> OneOrMany<&'a T> for &'a [T]
+where
+    &'a T: Single,
+{
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_many(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> OneOrMany<&'a T> for &'a Vec<T>
+where
+    &'a T: Single,
+{
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_many(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> OneOrMany<&'a T> for &'a [T; N]
+where
+    &'a T: Single,
+{
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_many(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Wraps any iterator-like thing of single values so that an `AnyOneOrMany` or `AnySet` input accepts it.
+///
+/// # Example
+/// ```
+/// use anyinput::{Many, OneOrMany};
+///
+/// let paths = Many(["a.bed", "b.txt"].into_iter().filter(|p| p.ends_with(".bed")));
+/// assert_eq!(paths.into_many().collect::<Vec<_>>(), ["a.bed"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Many<I>(pub I);
+
+impl<I: IntoIterator> OneOrMany<I::Item> for Many<I>
+where
+    I::Item: Single,
+{
+    type IntoIter = I::IntoIter;
+
+    fn into_many(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
@@ -0,0 +1,101 @@
+// Runtime support for AnyRange. The `#[anyinput]` macro bounds AnyRange inputs by `RangeBounds` and converts them
+// with `anyinput::resolve_range(range, len)?`.
+
+use std::fmt;
+use std::ops::{Bound, Range, RangeBounds};
+
+/// An unsigned integer type that can index a range, for example, `usize` or `u64`.
+pub trait RangeIndex: Copy + Ord + fmt::Debug + fmt::Display {
+    /// The smallest index, zero.
+    const ZERO: Self;
+
+    /// Adds one, returning `None` on overflow.
+    fn checked_increment(self) -> Option<Self>;
+}
+
+macro_rules! impl_range_index {
+    ($($t:ty),*) => {
+        $(
+            impl RangeIndex for $t {
+                const ZERO: Self = 0;
+
+                fn checked_increment(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+            }
+        )*
+    };
+}
+
+impl_range_index!(usize, u8, u16, u32, u64, u128);
+
+/// The error from [`resolve_range`] when a range doesn't fit within the length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RangeError<T = usize> {
+    /// The range's end is greater than the length, for example, `2..12` for a length of 10.
+    EndOutOfBounds {
+        /// The exclusive end of the range.
+        end: T,
+        /// The length the range must fit within.
+        len: T,
+    },
+    /// The range's start is greater than its end, for example, `5..3`.
+    StartAfterEnd {
+        /// The inclusive start of the range.
+        start: T,
+        /// The exclusive end of the range.
+        end: T,
+    },
+    /// An inclusive end, or an exclusive start, is the largest value of the index type.
+    Overflow,
+}
+
+impl<T: fmt::Display> fmt::Display for RangeError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeError::EndOutOfBounds { end, len } => {
+                write!(f, "range end {end} is out of bounds for length {len}")
+            }
+            RangeError::StartAfterEnd { start, end } => {
+                write!(f, "range start {start} is greater than range end {end}")
+            }
+            RangeError::Overflow => write!(f, "range bound overflows its index type"),
+        }
+    }
+}
+
+impl<T: fmt::Debug + fmt::Display> std::error::Error for RangeError<T> {}
+
+/// Resolves any range, for example, `2..5`, `..5`, `2..=4`, `2..`, or `..`, into a `Range` within `0..len`.
+///
+/// # Example
+/// ```
+/// use anyinput::{resolve_range, RangeError};
+///
+/// let len: usize = 10;
+/// assert_eq!(resolve_range(2.., len), Ok(2..10));
+/// assert_eq!(resolve_range(..=4, len), Ok(0..5));
+/// assert_eq!(resolve_range(2..12, len), Err(RangeError::EndOutOfBounds { end: 12, len }));
+/// ```
+pub fn resolve_range<T: RangeIndex>(
+    range: impl RangeBounds<T>,
+    len: T,
+) -> Result<Range<T>, RangeError<T>> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_increment().ok_or(RangeError::Overflow)?,
+        Bound::Unbounded => T::ZERO,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_increment().ok_or(RangeError::Overflow)?,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    if end > len {
+        return Err(RangeError::EndOutOfBounds { end, len });
+    }
+    if start > end {
+        return Err(RangeError::StartAfterEnd { start, end });
+    }
+    Ok(start..end)
+}
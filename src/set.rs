@@ -0,0 +1,241 @@
+// Runtime support for AnySet. The `#[anyinput]` macro bounds AnySet inputs by `IntoSet` and converts them
+// with `.into_set()`. Inputs that already support lookup, for example, a `HashSet`, a range, or a `Vec`, are used
+// as is. Iterators, which don't, are collected into a `HashSet`.
+
+use crate::Many;
+use std::collections::{BTreeSet, HashSet};
+use std::hash::{BuildHasher, Hash};
+use std::iter::{Cloned, Copied, Filter, Map};
+use std::ops::{Range, RangeInclusive};
+
+/// A collection that can tell if it contains a value.
+///
+/// Implement it for your own collection type, then implement [`IntoSet`] to accept it as an `AnySet`.
+pub trait Contains<T: ?Sized> {
+    /// Tells if the value is present.
+    fn contains(&self, value: &T) -> bool;
+}
+
+/// Converts a set-like or array-like thing into a [`Set`] that can tell if it contains a value.
+///
+/// `HashSet`, `BTreeSet`, and ranges, borrowed or moved, are used as is. So are `Vec`s, arrays, and slices, which
+/// search their elements and need only `PartialEq`. Iterators made with `copied`, `cloned`, `filter`, or `map`, for
+/// example, `ids.iter().copied()`, are collected into a `HashSet`. Wrap any other iterator in [`Many`], for example,
+/// `Many(a.chain(b))`.
+/// (A range is itself an iterator, so a blanket implementation for iterators isn't possible.)
+pub trait IntoSet<T> {
+    /// The collection that does the lookup.
+    type Lookup: Contains<T>;
+
+    /// Converts into a [`Set`].
+    fn into_set(self) -> Set<Self::Lookup>;
+}
+
+/// The result of converting an `AnySet` input. Its `contains` method needs no import.
+///
+/// # Example
+/// ```
+/// use anyinput::IntoSet;
+///
+/// let set = vec![1, 2, 3].into_set();
+/// assert!(set.contains(&2));
+/// assert!(!(0..2).into_set().contains(&2));
+/// assert!((0..10).filter(|n| n % 2 == 0).into_set().contains(&4));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Set<S>(S);
+
+impl<S> Set<S> {
+    /// Tells if the value is present.
+    pub fn contains<T: ?Sized>(&self, value: &T) -> bool
+    where
+        S: Contains<T>,
+    {
+        self.0.contains(value)
+    }
+
+    /// Returns the underlying collection, for example, a `HashSet`.
+    pub fn into_inner(self) -> S {
+        self.0
+    }
+}
+
+impl<T: Hash + Eq, S: BuildHasher> Contains<T> for HashSet<T, S> {
+    fn contains(&self, value: &T) -> bool {
+        HashSet::contains(self, value)
+    }
+}
+
+impl<T: Ord> Contains<T> for BTreeSet<T> {
+    fn contains(&self, value: &T) -> bool {
+        BTreeSet::contains(self, value)
+    }
+}
+
+impl<T: PartialOrd> Contains<T> for Range<T> {
+    fn contains(&self, value: &T) -> bool {
+        Range::contains(self, value)
+    }
+}
+
+impl<T: PartialOrd> Contains<T> for RangeInclusive<T> {
+    fn contains(&self, value: &T) -> bool {
+        RangeInclusive::contains(self, value)
+    }
+}
+
+impl<T: PartialEq> Contains<T> for [T] {
+    fn contains(&self, value: &T) -> bool {
+        <[T]>::contains(self, value)
+    }
+}
+
+impl<T: PartialEq> Contains<T> for Vec<T> {
+    fn contains(&self, value: &T) -> bool {
+        <[T]>::contains(self, value)
+    }
+}
+
+impl<T: PartialEq, const N: usize> Contains<T> for [T; N] {
+    fn contains(&self, value: &T) -> bool {
+        <[T]>::contains(self, value)
+    }
+}
+
+impl<T, C: Contains<T> + ?Sized> Contains<T> for &C {
+    fn contains(&self, value: &T) -> bool {
+        (**self).contains(value)
+    }
+}
+
+impl<T: Hash + Eq, S: BuildHasher> IntoSet<T> for HashSet<T, S> {
+    type Lookup = Self;
+
+    fn into_set(self) -> Set<Self> {
+        Set(self)
+    }
+}
+
+impl<T: Hash + Eq, S: BuildHasher> IntoSet<T> for &HashSet<T, S> {
+    type Lookup = Self;
+
+    fn into_set(self) -> Set<Self> {
+        Set(self)
+    }
+}
+
+impl<T: Ord> IntoSet<T> for BTreeSet<T> {
+    type Lookup = Self;
+
+    fn into_set(self) -> Set<Self> {
+        Set(self)
+    }
+}
+
+impl<T: Ord> IntoSet<T> for &BTreeSet<T> {
+    type Lookup = Self;
+
+    fn into_set(self) -> Set<Self> {
+        Set(self)
+    }
+}
+
+impl<T: PartialOrd> IntoSet<T> for Range<T> {
+    type Lookup = Self;
+
+    fn into_set(self) -> Set<Self> {
+        Set(self)
+    }
+}
+
+impl<T: PartialOrd> IntoSet<T> for RangeInclusive<T> {
+    type Lookup = Self;
+
+    fn into_set(self) -> Set<Self> {
+        Set(self)
+    }
+}
+
+impl<T: PartialEq> IntoSet<T> for Vec<T> {
+    type Lookup = Self;
+
+    fn into_set(self) -> Set<Self> {
+        Set(self)
+    }
+}
+
+impl<T: PartialEq, const N: usize> IntoSet<T> for [T; N] {
+    type Lookup = Self;
+
+    fn into_set(self) -> Set<Self> {
+        Set(self)
+    }
+}
+
+impl<T: PartialEq> IntoSet<T> for &[T] {
+    type Lookup = Self;
+
+    fn into_set(self) -> Set<Self> {
+        Set(self)
+    }
+}
+
+impl<'a, T: PartialEq> IntoSet<T> for &'a Vec<T> {
+    type Lookup = &'a [T];
+
+    fn into_set(self) -> Set<&'a [T]> {
+        self.as_slice().into_set()
+    }
+}
+
+impl<'a, T: PartialEq, const N: usize> IntoSet<T> for &'a [T; N] {
+    type Lookup = &'a [T];
+
+    fn into_set(self) -> Set<&'a [T]> {
+        self.as_slice().into_set()
+    }
+}
+
+impl<'a, T: Hash + Eq + Copy + 'a, I: Iterator<Item = &'a T>> IntoSet<T> for Copied<I> {
+    type Lookup = HashSet<T>;
+
+    fn into_set(self) -> Set<HashSet<T>> {
+        Set(self.collect())
+    }
+}
+
+impl<'a, T: Hash + Eq + Clone + 'a, I: Iterator<Item = &'a T>> IntoSet<T> for Cloned<I> {
+    type Lookup = HashSet<T>;
+
+    fn into_set(self) -> Set<HashSet<T>> {
+        Set(self.collect())
+    }
+}
+
+impl<T: Hash + Eq, I: Iterator<Item = T>, P: FnMut(&T) -> bool> IntoSet<T> for Filter<I, P> {
+    type Lookup = HashSet<T>;
+
+    fn into_set(self) -> Set<HashSet<T>> {
+        Set(self.collect())
+    }
+}
+
+impl<T: Hash + Eq, I: Iterator, F: FnMut(I::Item) -> T> IntoSet<T> for Map<I, F> {
+    type Lookup = HashSet<T>;
+
+    fn into_set(self) -> Set<HashSet<T>> {
+        Set(self.collect())
+    }
+}
+
+// Any other iterator, for example, "Many(a.chain(b))".
+impl<I: IntoIterator> IntoSet<I::Item> for Many<I>
+where
+    I::Item: Hash + Eq,
+{
+    type Lookup = HashSet<I::Item>;
+
+    fn into_set(self) -> Set<HashSet<I::Item>> {
+        Set(self.0.into_iter().collect())
+    }
+}
@@ -0,0 +1,104 @@
+// Runtime support for AnyTryIter. The `#[anyinput]` macro converts AnyTryIter inputs into a `TryIter`,
+// whose items are `Result<T, E>` whether the input yields `T` or `Result<T, E>`.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+/// An item that can be read as a `Result<T, E>`. A plain `T` becomes `Ok(T)` and a `Result<T, E>` stays as is.
+///
+/// # Example
+/// ```
+/// use anyinput::TryItem;
+///
+/// let item: Result<usize, String> = 3.into_result();
+/// assert_eq!(item, Ok(3));
+/// let item: Result<usize, String> = Err::<usize, String>("bad".to_string()).into_result();
+/// assert_eq!(item, Err("bad".to_string()));
+/// ```
+pub trait TryItem<T, E> {
+    /// Returns the item as a `Result`.
+    fn into_result(self) -> Result<T, E>;
+}
+
+impl<T, E> TryItem<T, E> for T {
+    fn into_result(self) -> Result<T, E> {
+        Ok(self)
+    }
+}
+
+impl<T, E> TryItem<T, E> for Result<T, E> {
+    fn into_result(self) -> Result<T, E> {
+        self
+    }
+}
+
+/// An iterator of `Result<T, E>`, made from an iterator whose items are `T` or `Result<T, E>`.
+///
+/// # Example
+/// ```
+/// use anyinput::TryIter;
+///
+/// let total: Result<usize, String> = TryIter::<_, usize, String>::new(vec![1, 2, 3]).sum();
+/// assert_eq!(total, Ok(6));
+/// let results: Vec<Result<usize, String>> = vec![Ok(1), Err("bad".to_string())];
+/// let total: Result<usize, String> = TryIter::<_, usize, String>::new(results).sum();
+/// assert_eq!(total, Err("bad".to_string()));
+/// ```
+pub struct TryIter<I, T, E> {
+    iter: I,
+    phantom: PhantomData<fn() -> Result<T, E>>,
+}
+
+impl<I: Iterator, T, E> TryIter<I, T, E>
+where
+    I::Item: TryItem<T, E>,
+{
+    /// Creates the iterator from anything iterator-like.
+    pub fn new(into_iter: impl IntoIterator<IntoIter = I>) -> Self {
+        TryIter {
+            iter: into_iter.into_iter(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I: Iterator, T, E> Iterator for TryIter<I, T, E>
+where
+    I::Item: TryItem<T, E>,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(TryItem::into_result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator, T, E> DoubleEndedIterator for TryIter<I, T, E>
+where
+    I::Item: TryItem<T, E>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(TryItem::into_result)
+    }
+}
+
+impl<I: ExactSizeIterator, T, E> ExactSizeIterator for TryIter<I, T, E> where I::Item: TryItem<T, E> {}
+
+impl<I: Clone, T, E> Clone for TryIter<I, T, E> {
+    fn clone(&self) -> Self {
+        TryIter {
+            iter: self.iter.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I: fmt::Debug, T, E> fmt::Debug for TryIter<I, T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TryIter").field(&self.iter).finish()
+    }
+}
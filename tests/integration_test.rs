@@ -185,6 +185,1150 @@ fn doc_ndarray() -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+#[test]
+fn extra_bounds() -> Result<(), anyhow::Error> {
+    #[anyinput]
+    fn len_and_last(
+        iter: AnyIter<usize, ExactSizeIterator + DoubleEndedIterator>,
+    ) -> (usize, usize) {
+        let len = iter.len();
+        (len, iter.rev().take(1).sum::<usize>())
+    }
+    assert_eq!(len_and_last(vec![1, 2, 3]), (3, 3));
+    assert_eq!(len_and_last(0..10), (10, 9));
+
+    #[anyinput]
+    fn twice(iter: AnyIter<AnyString, Clone>) -> usize {
+        let count = iter.clone().count();
+        count + iter.map(|s| s.as_ref().len()).sum::<usize>()
+    }
+    assert_eq!(twice(["a", "bb"]), 5);
+
+    #[anyinput]
+    fn any_str_len(s: AnyString<Clone + Send>) -> usize {
+        s.len()
+    }
+    assert_eq!(any_str_len("abc".to_string()), 3);
+
+    #[anyinput]
+    fn path_depth(path: AnyPath<Send>) -> usize {
+        std::thread::scope(|scope| {
+            scope
+                .spawn(move || path.components().count())
+                .join()
+                .unwrap()
+        })
+    }
+    assert_eq!(path_depth("a/b/c"), 3);
+
+    #[anyinput]
+    fn cloned_len(s: AnyString<Clone>) -> usize {
+        s.len()
+    }
+    assert_eq!(cloned_len("ab"), 2);
+    Ok(())
+}
+
+#[test]
+fn only_and_except() -> Result<(), anyhow::Error> {
+    // A user type with the name of a special.
+    struct AnyPath {
+        depth: usize,
+    }
+
+    #[anyinput(except(AnyPath))]
+    fn total_depth(paths: AnyIter<AnyPath>) -> usize {
+        paths.map(|path| path.depth).sum()
+    }
+    assert_eq!(
+        total_depth(vec![AnyPath { depth: 2 }, AnyPath { depth: 3 }]),
+        5
+    );
+
+    #[anyinput(only(AnyIter, AnyString))]
+    fn total_len(paths: AnyIter<AnyPath>, s: AnyString) -> usize {
+        paths.map(|path| path.depth).sum::<usize>() + s.len()
+    }
+    assert_eq!(total_len([AnyPath { depth: 1 }], "abc"), 4);
+    Ok(())
+}
+
+#[test]
+fn aliases_and_qualified_paths() -> Result<(), anyhow::Error> {
+    #[anyinput(alias(Text = AnyString, FilePath = AnyPath))]
+    fn text_and_path_len(text: Text, path: FilePath) -> usize {
+        text.len() + path.iter().count()
+    }
+    assert_eq!(text_and_path_len("abc", "d/e/f"), 6);
+
+    #[anyinput]
+    fn total_len(texts: anyinput::AnyIter<anyinput::AnyString>) -> usize {
+        texts.map(|text| text.as_ref().len()).sum()
+    }
+    assert_eq!(total_len(vec!["a".to_string(), "bb".to_string()]), 3);
+    Ok(())
+}
+
+// The macro keeps a use of each marker named, so these imports, which are only for docs and IDEs,
+// aren't reported as unused.
+mod imported_markers {
+    use anyinput::{anyinput, AnyArray, AnyIter, AnyMap, AnyPath, AnyString};
+
+    #[anyinput]
+    pub fn total_len(strings: AnyIter<AnyString>, array: AnyArray<AnyPath>) -> usize {
+        let len: usize = strings.map(|s| s.as_ref().len()).sum();
+        len + array.len()
+    }
+
+    #[anyinput]
+    pub fn total(map: AnyMap<AnyString, usize>) -> usize {
+        map.map(|(_, value)| value).sum()
+    }
+}
+
+#[test]
+fn markers() -> Result<(), anyhow::Error> {
+    assert_eq!(imported_markers::total_len(["a", "bb"], ["c/d"]), 4);
+    assert_eq!(imported_markers::total([("a", 1), ("b", 2)]), 3);
+
+    #[anyinput]
+    fn total_len2(strings: anyinput::AnyIter<anyinput::AnyString>) -> usize {
+        strings.map(|s| s.as_ref().len()).sum()
+    }
+    assert_eq!(total_len2(["a", "bb"]), 3);
+    Ok(())
+}
+
+#[test]
+fn send_static() -> Result<(), anyhow::Error> {
+    use std::thread;
+
+    #[anyinput(send, static)]
+    fn spawn_len_sum(iter: AnyIter<AnyString>) -> thread::JoinHandle<usize> {
+        thread::spawn(move || iter.map(|s| s.as_ref().len()).sum())
+    }
+    assert_eq!(spawn_len_sum(vec!["a", "bb", "ccc"]).join().unwrap(), 6);
+    let strings = vec!["a".to_string(), "bb".to_string()];
+    assert_eq!(spawn_len_sum(strings).join().unwrap(), 3);
+
+    #[anyinput]
+    fn spawn_sum_plus_len(#[anyinput(send, static)] iter: AnyIter<usize>, s: AnyString) -> usize {
+        let handle = thread::spawn(move || iter.sum::<usize>());
+        handle.join().unwrap() + s.len()
+    }
+    let local = "abc".to_string();
+    assert_eq!(spawn_sum_plus_len(1..=3, &local), 9);
+    Ok(())
+}
+
+#[test]
+fn one_bytes_input() -> Result<(), anyhow::Error> {
+    #[anyinput]
+    fn checksum(bytes: AnyBytes) -> u32 {
+        bytes.iter().map(|&b| b as u32).sum()
+    }
+    assert_eq!(checksum(vec![1u8, 2, 3]), 6); // Vec<u8>
+    let array: &[u8; 3] = &[1, 2, 3];
+    assert_eq!(checksum(array), 6); // &[u8; N]
+    assert_eq!(checksum([1u8, 2, 3].as_slice()), 6); // &[u8]
+    assert_eq!(checksum("ab"), 97 + 98); // &str
+    let string = "ab".to_string();
+    assert_eq!(checksum(&string), 97 + 98); // &String
+    assert_eq!(checksum(string), 97 + 98); // String
+
+    #[anyinput]
+    fn total_len(chunks: AnyIter<AnyBytes>) -> usize {
+        chunks.map(|chunk| chunk.as_ref().len()).sum()
+    }
+    assert_eq!(total_len([b"abc".as_slice(), b"de"]), 5);
+    Ok(())
+}
+
+#[test]
+fn iter_os_str() -> Result<(), anyhow::Error> {
+    use std::ffi::{OsStr, OsString};
+    use std::process::Command;
+
+    #[anyinput]
+    fn command_args(program: AnyOsStr, args: AnyIter<AnyOsStr>) -> Vec<OsString> {
+        let mut command = Command::new(program);
+        command.args(args);
+        command.get_args().map(OsStr::to_os_string).collect()
+    }
+
+    let from_env = command_args("echo", std::env::args_os().take(1));
+    assert_eq!(from_env.len(), 1);
+    let strings = vec!["-n".to_string(), "hi".to_string()];
+    assert_eq!(command_args("echo", strings), ["-n", "hi"]);
+    let strs: &[&str] = &["-n", "hi"];
+    assert_eq!(command_args(OsString::from("echo"), strs), ["-n", "hi"]);
+
+    #[anyinput]
+    fn var_or_default(name: AnyOsStr) -> OsString {
+        std::env::var_os(name).unwrap_or_default()
+    }
+    assert_eq!(var_or_default("ANYINPUT_SURELY_UNSET"), "");
+    Ok(())
+}
+
+#[test]
+fn mut_inputs() {
+    #[anyinput]
+    fn sort_and_fill(array: AnyArrayMut<i32>, rest: AnyArrayMut<i32>) {
+        array.sort();
+        rest.fill(array[0]);
+    }
+    let mut vec = vec![3, 1, 2];
+    let mut array = [7, 8];
+    sort_and_fill(&mut vec, &mut array);
+    assert_eq!(vec, [1, 2, 3]);
+    assert_eq!(array, [1, 1]);
+    let slice: &mut [i32] = &mut vec;
+    sort_and_fill(slice, &mut array);
+
+    #[anyinput]
+    fn shout(s: AnyStringMut) {
+        s.make_ascii_uppercase();
+    }
+    let mut string = "hello".to_string();
+    shout(&mut string);
+    assert_eq!(string, "HELLO");
+    let str: &mut str = &mut string;
+    shout(str);
+
+    // Nested, the binding must be 'mut' to call '.as_mut()'.
+    #[anyinput]
+    fn shout_all(strings: AnyIter<AnyStringMut>) {
+        for mut s in strings {
+            s.as_mut().make_ascii_uppercase();
+        }
+    }
+    let mut strings = vec!["a".to_string(), "b".to_string()];
+    shout_all(strings.iter_mut());
+    assert_eq!(strings, ["A", "B"]);
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn ndarray_mut_input() {
+    #[anyinput]
+    fn normalize(a: AnyNdArrayMut<f32>) {
+        let sum = a.sum();
+        a.mapv_inplace(|x| x / sum);
+    }
+    let mut vec = vec![1.0, 3.0];
+    normalize(&mut vec);
+    assert_eq!(vec, [0.25, 0.75]);
+    let mut array = [2.0, 2.0];
+    normalize(&mut array);
+    assert_eq!(array, [0.5, 0.5]);
+    let mut nd_array = ndarray::array![1.0, 1.0, 2.0];
+    normalize(&mut nd_array);
+    assert_eq!(nd_array, ndarray::array![0.25, 0.25, 0.5]);
+}
+
+#[test]
+fn cow_inputs() {
+    use std::borrow::Cow;
+    use std::path::{Path, PathBuf};
+
+    // Keeps an owned input without copying it.
+    #[anyinput]
+    fn cache(cache: &mut Vec<String>, s: AnyCow<str>) {
+        cache.push(s.into_owned());
+    }
+    let mut strings = Vec::new();
+    let string = "hello".to_string();
+    let ptr = string.as_ptr();
+    cache(&mut strings, string);
+    assert_eq!(strings[0].as_ptr(), ptr);
+    cache(&mut strings, "world");
+    assert_eq!(strings, ["hello", "world"]);
+
+    #[anyinput]
+    fn is_borrowed(path: AnyCow<Path>, array: AnyCow<[u8]>) -> (bool, bool) {
+        (
+            matches!(path, Cow::Borrowed(_)),
+            matches!(array, Cow::Borrowed(_)),
+        )
+    }
+    assert_eq!(is_borrowed(Path::new("a"), &[1u8, 2][..]), (true, true));
+    let path_buf = PathBuf::from("a");
+    let vec = vec![1u8, 2];
+    assert_eq!(is_borrowed(&path_buf, &vec), (true, true));
+    assert_eq!(is_borrowed(path_buf, vec), (false, false));
+
+    #[anyinput]
+    fn total_len(strings: AnyIter<AnyCow<str>>) -> usize {
+        strings.map(|s| s.into().len()).sum()
+    }
+    assert_eq!(total_len(["a", "bc"]), 3);
+    assert_eq!(total_len(vec!["a".to_string()]), 1);
+}
+
+#[test]
+fn into_and_try_into_inputs() -> Result<(), anyhow::Error> {
+    #[derive(Debug, PartialEq)]
+    struct Config {
+        verbose: bool,
+    }
+    impl From<bool> for Config {
+        fn from(verbose: bool) -> Self {
+            Config { verbose }
+        }
+    }
+
+    #[anyinput]
+    fn describe(config: AnyInto<Config>) -> String {
+        format!("verbose={}", config.verbose)
+    }
+    assert_eq!(describe(true), "verbose=true");
+    assert_eq!(describe(Config { verbose: false }), "verbose=false");
+
+    #[anyinput]
+    fn port(port: AnyTryInto<u16>) -> Result<u16, anyhow::Error> {
+        Ok(port)
+    }
+    assert_eq!(port(8080u64)?, 8080);
+    assert_eq!(port(8080u16)?, 8080);
+    let error = port(70_000u32).unwrap_err();
+    assert!(error.is::<std::num::TryFromIntError>());
+
+    // Nested, convert the error to the function's error type.
+    #[anyinput]
+    fn ports(ports: AnyIter<AnyTryInto<u16>>) -> Result<Vec<u16>, anyhow::Error> {
+        let mut result = Vec::new();
+        for port in ports {
+            result.push(port.try_into().map_err(Into::<anyhow::Error>::into)?);
+        }
+        Ok(result)
+    }
+    assert_eq!(ports([80i32, 443])?, [80, 443]);
+    assert!(ports([-1i32]).is_err());
+    Ok(())
+}
+
+#[test]
+fn option_inputs() {
+    #[anyinput]
+    fn row_count(max_rows: AnyOption<usize>) -> usize {
+        max_rows.unwrap_or(100)
+    }
+    assert_eq!(row_count(5), 5);
+    assert_eq!(row_count(Some(5)), 5);
+    assert_eq!(row_count(None), 100);
+
+    #[anyinput]
+    fn greeting(name: AnyOption<AnyString>) -> String {
+        let name: Option<&str> = name.as_ref().map(AsRef::as_ref);
+        format!("Hello, {}!", name.unwrap_or("world"))
+    }
+    assert_eq!(greeting("Ada"), "Hello, Ada!");
+    assert_eq!(greeting("Ada".to_string()), "Hello, Ada!");
+    // 'Some(..)' and 'None' need the nested type named.
+    assert_eq!(greeting::<&str, _>(Some("Ada")), "Hello, Ada!");
+    assert_eq!(greeting::<&str, _>(None), "Hello, world!");
+
+    #[anyinput]
+    fn first_len(paths: AnyOption<AnyIter<AnyPath>>) -> usize {
+        paths
+            .and_then(|paths| paths.into_iter().next())
+            .map_or(0, |path| path.as_ref().iter().count())
+    }
+    assert_eq!(first_len(["a/b"]), 2);
+    assert_eq!(first_len::<&str, Vec<&str>, _>(None), 0);
+}
+
+#[test]
+fn map_inputs() {
+    use anyinput::Lookup;
+    use std::collections::{BTreeMap, HashMap};
+    use std::path::{Path, PathBuf};
+
+    #[anyinput]
+    fn file_for(files: AnyMap<AnyString, AnyPath>, name: AnyString) -> Option<PathBuf> {
+        let files: Lookup<_, _> = files.collect();
+        files.get(name).map(|path| path.as_ref().to_path_buf())
+    }
+    let hash_map = HashMap::from([("data".to_string(), PathBuf::from("data.csv"))]);
+    assert_eq!(file_for(&hash_map, "data"), Some(PathBuf::from("data.csv")));
+    assert_eq!(file_for(hash_map, "other"), None);
+    let b_tree_map = BTreeMap::from([("data", Path::new("data.csv"))]);
+    assert_eq!(
+        file_for(b_tree_map, "data"),
+        Some(PathBuf::from("data.csv"))
+    );
+    assert_eq!(
+        file_for(vec![("data", "a"), ("data", "b")], "data"),
+        Some(PathBuf::from("b"))
+    );
+    assert_eq!(
+        file_for([("data", "data.csv")], "data"),
+        Some(PathBuf::from("data.csv"))
+    );
+
+    #[anyinput]
+    fn settings_len(settings: AnyMap<AnyString, usize, ExactSizeIterator>) -> usize {
+        settings.len()
+    }
+    assert_eq!(settings_len([("a", 1), ("b", 2)]), 2);
+
+    let lookup: Lookup<&str, usize> = [("a", 1), ("b", 2)].into_iter().collect();
+    assert!(lookup.contains_key("b"));
+    assert!(!lookup.is_empty());
+    assert_eq!(lookup.iter().map(|(_, v)| v).sum::<usize>(), 3);
+
+    #[anyinput]
+    fn parent_of(parents: AnyMap<usize, usize>, id: usize) -> Option<usize> {
+        let parents: Lookup<_, _> = parents.collect();
+        parents.get_borrow(&id).copied()
+    }
+    let hash_map = HashMap::from([(1, 0), (2, 1)]);
+    assert_eq!(parent_of(hash_map, 2), Some(1));
+    assert_eq!(parent_of(BTreeMap::from([(1, 0)]), 2), None);
+    assert_eq!(parent_of(vec![(3, 1), (3, 2)], 3), Some(2));
+
+    let lookup: Lookup<String, usize> = [("a".to_string(), 1)].into_iter().collect();
+    assert!(lookup.contains_key_borrow("a"));
+}
+
+#[test]
+fn set_input() {
+    use anyinput::IntoSet;
+    use std::collections::{BTreeSet, HashSet};
+
+    #[anyinput]
+    fn keep(ids: AnySet<u32>, values: AnyIter<u32>) -> Vec<u32> {
+        values.filter(|id| ids.contains(id)).collect()
+    }
+    let hash_set = HashSet::from([1, 3]);
+    assert_eq!(keep(&hash_set, [1, 2, 3]), [1, 3]);
+    assert_eq!(keep(hash_set, [1, 2, 3]), [1, 3]);
+    let b_tree_set = BTreeSet::from([2]);
+    assert_eq!(keep(&b_tree_set, [1, 2, 3]), [2]);
+    assert_eq!(keep(b_tree_set, [1, 2, 3]), [2]);
+    let vec = vec![3, 3];
+    assert_eq!(keep(&vec, [1, 2, 3]), [3]);
+    assert_eq!(keep(vec.as_slice(), [1, 2, 3]), [3]);
+    assert_eq!(keep(vec, [1, 2, 3]), [3]);
+    assert_eq!(keep([1], [1, 2, 3]), [1]);
+    let array = [1, 2];
+    let array_ref: &[u32; 2] = &array;
+    assert_eq!(keep(array_ref, [1, 2, 3]), [1, 2]);
+    assert_eq!(keep(array, [1, 2, 3]), [1, 2]);
+    assert_eq!(keep(2..3, [1, 2, 3]), [2]);
+    assert_eq!(keep(2..=3, [1, 2, 3]), [2, 3]);
+    let ids = vec![1, 3];
+    assert_eq!(keep(ids.iter().copied(), [1, 2, 3]), [1, 3]);
+    assert_eq!(keep(ids.iter().cloned(), [2, 3]), [3]);
+    assert_eq!(keep((0..10).filter(|n| n % 2 == 0), [1, 2, 3]), [2]);
+    assert_eq!(keep(ids.iter().map(|id| id + 1), [1, 2, 3]), [2]);
+    assert_eq!(
+        keep(anyinput::Many(ids.into_iter().chain([2])), [1, 2, 3]),
+        [1, 2, 3]
+    );
+
+    // A set, range, or array-like thing is used as is. Only an iterator builds a HashSet.
+    let hash_set = HashSet::from([1]);
+    assert!(std::ptr::eq((&hash_set).into_set().into_inner(), &hash_set));
+    assert_eq!((0..5).into_set().into_inner(), 0..5);
+    let vec = vec![1, 2];
+    assert!(std::ptr::eq((&vec).into_set().into_inner(), vec.as_slice()));
+    let ids: HashSet<u32> = vec.iter().copied().into_set().into_inner();
+    assert_eq!(ids.len(), 2);
+
+    // An array-like thing needs only PartialEq.
+    #[anyinput]
+    fn has_point(points: AnySet<(f64, f64)>, point: (f64, f64)) -> bool {
+        points.contains(&point)
+    }
+    assert!(has_point([(0.5, 1.0), (2.0, 0.0)], (2.0, 0.0)));
+    let points = vec![(0.5, 1.0)];
+    assert!(!has_point(points.as_slice(), (1.0, 0.5)));
+
+    #[anyinput]
+    fn strings_kept(names: AnySet<String>, candidates: AnyIter<String>) -> usize {
+        candidates.filter(|name| names.contains(name)).count()
+    }
+    let names = vec!["a".to_string()];
+    assert_eq!(strings_kept(&names, ["a".to_string(), "b".to_string()]), 1);
+}
+
+#[test]
+fn range_input() -> Result<(), anyhow::Error> {
+    use anyinput::RangeError;
+
+    #[anyinput(len = data.len())]
+    fn sum(range: AnyRange, data: AnyArray<u32>) -> Result<u32, RangeError> {
+        Ok(data[range].iter().sum())
+    }
+    let data = vec![1, 2, 3, 4];
+    assert_eq!(sum(1..3, &data)?, 5);
+    assert_eq!(sum(1..=3, &data)?, 9);
+    assert_eq!(sum(..2, &data)?, 3);
+    assert_eq!(sum(2.., &data)?, 7);
+    assert_eq!(sum(.., &data)?, 10);
+    assert_eq!(sum(4..4, &data)?, 0);
+    assert_eq!(
+        sum(2..5, &data),
+        Err(RangeError::EndOutOfBounds { end: 5, len: 4 })
+    );
+    let (start, end) = (3, 2);
+    assert_eq!(
+        sum(start..end, &data),
+        Err(RangeError::StartAfterEnd { start: 3, end: 2 })
+    );
+    assert_eq!(sum(..=usize::MAX, &data), Err(RangeError::Overflow));
+
+    // Per input, with another index type, and into an anyhow::Error.
+    #[anyinput]
+    fn count(#[anyinput(len = 100)] range: AnyRange<u64>) -> anyhow::Result<u64> {
+        Ok(range.end - range.start)
+    }
+    assert_eq!(count(10..20u64)?, 10);
+    assert_eq!(
+        count(10..200u64).unwrap_err().to_string(),
+        "range end 200 is out of bounds for length 100"
+    );
+    Ok(())
+}
+
+#[test]
+fn iter_borrow_input() {
+    #[anyinput]
+    fn total(iter: AnyIterBorrow<usize>) -> usize {
+        iter.map(|x| *x).sum()
+    }
+    let vec = vec![1, 2, 3];
+    assert_eq!(total(&vec), 6);
+    assert_eq!(total(vec.iter()), 6);
+    assert_eq!(total(1..4), 6);
+    assert_eq!(total(vec), 6);
+
+    #[anyinput]
+    fn lens(strings: AnyIterBorrow<str, DoubleEndedIterator>) -> Vec<usize> {
+        strings.rev().map(|s| s.len()).collect()
+    }
+    let strings = vec!["a".to_string(), "bc".to_string()];
+    assert_eq!(lens(["a", "bc"]), [2, 1]);
+    assert_eq!(lens(strings), [2, 1]);
+
+    #[anyinput]
+    fn sum_all(iters: AnyIter<AnyIterBorrow<u8>>) -> u32 {
+        iters
+            .flat_map(anyinput::BorrowIter::<_, u8>::new)
+            .map(|x| *x as u32)
+            .sum()
+    }
+    assert_eq!(sum_all([vec![1u8, 2], vec![3]]), 6);
+    assert_eq!(sum_all([0..2u8, 5..6]), 6);
+}
+
+#[test]
+fn iter_multi_input() {
+    #[anyinput]
+    fn variance(data: AnyIterMulti<f64, ExactSizeIterator>) -> f64 {
+        let len = data.iter().len() as f64;
+        let mean = data.iter().map(|x| *x).sum::<f64>() / len;
+        data.iter().map(|x| (*x - mean) * (*x - mean)).sum::<f64>() / len
+    }
+    assert_eq!(variance([1.0, 3.0]), 1.0);
+    let mut vec = vec![2.0, 4.0];
+    vec.push(6.0);
+    assert_eq!(variance(&vec), 8.0 / 3.0);
+    assert_eq!(variance(vec.as_slice()), 8.0 / 3.0);
+    assert_eq!(variance(vec.iter()), 8.0 / 3.0);
+
+    #[anyinput]
+    fn checked_total(data: AnyIterMulti<usize>) -> Option<usize> {
+        if data.iter().any(|x| *x > 100) {
+            return None;
+        }
+        let mut total = 0;
+        for x in &data {
+            total += *x;
+        }
+        Some(total)
+    }
+    let ids = vec![1, 2, 3];
+    assert_eq!(checked_total(&ids), Some(6));
+    assert_eq!(checked_total([1, 200].as_slice()), None);
+    assert_eq!(checked_total(0..4), Some(6));
+
+    #[anyinput]
+    fn min_and_max(data: AnyIterMulti<usize>) -> (usize, usize) {
+        let min = data.iter().map(|x| *x).min().unwrap();
+        (min, data.into_iter().map(|x| *x).max().unwrap())
+    }
+    assert_eq!(min_and_max(3..7), (3, 6));
+}
+
+#[test]
+fn try_iter_input() -> Result<(), anyhow::Error> {
+    use std::io::{self, BufRead};
+
+    #[anyinput]
+    fn total_len(lines: AnyTryIter<AnyString, io::Error>) -> Result<usize, io::Error> {
+        let mut total = 0;
+        for line in lines {
+            total += line?.as_ref().len();
+        }
+        Ok(total)
+    }
+    let lines = io::Cursor::new("a\nbc\n").lines();
+    assert_eq!(total_len::<String, _>(lines)?, 3);
+    assert_eq!(total_len(["a", "bc"])?, 3);
+    let err = total_len::<&str, _>(vec![Ok("a"), Err(io::Error::other("bad"))]);
+    assert_eq!(err.unwrap_err().to_string(), "bad");
+
+    #[anyinput]
+    fn count(items: AnyTryIter<u32, String, ExactSizeIterator>) -> Result<u32, String> {
+        let len = items.len() as u32;
+        let total = items.sum::<Result<u32, String>>()?;
+        Ok(total + len)
+    }
+    assert_eq!(count([1, 2]), Ok(5));
+    assert_eq!(
+        count([Ok(1), Err("bad".to_string())]),
+        Err("bad".to_string())
+    );
+    Ok(())
+}
+
+#[test]
+fn one_or_many_input() {
+    use anyinput::Many;
+    use std::path::{Path, PathBuf};
+
+    #[anyinput]
+    fn count_beds(paths: AnyOneOrMany<AnyPath>) -> usize {
+        paths
+            .filter(|path| path.as_ref().extension() == Some("bed".as_ref()))
+            .count()
+    }
+    assert_eq!(count_beds("a.bed"), 1);
+    assert_eq!(count_beds("a.txt".to_string()), 0);
+    assert_eq!(count_beds(Path::new("a.bed")), 1);
+    assert_eq!(count_beds(PathBuf::from("a.bed")), 1);
+    assert_eq!(count_beds(["a.bed", "b.bed"]), 2);
+    assert_eq!(count_beds(&["a.bed", "b.txt"]), 1);
+    let paths = vec![PathBuf::from("a.bed"), PathBuf::from("b.bed")];
+    assert_eq!(count_beds(&paths), 2);
+    assert_eq!(count_beds(paths.as_slice()), 2);
+    assert_eq!(count_beds(Many(paths.iter().skip(1))), 1);
+    assert_eq!(count_beds(paths), 2);
+
+    #[anyinput]
+    fn total_len(names: AnyOneOrMany<AnyString>) -> usize {
+        names.map(|name| name.as_ref().len()).sum()
+    }
+    let name = "abc".to_string();
+    assert_eq!(total_len(&name), 3);
+    assert_eq!(total_len(vec![name, "de".to_string()]), 5);
+}
+
+#[test]
+fn mixed_inputs() -> Result<(), anyhow::Error> {
+    use anyinput::{args, Arg};
+    use std::borrow::Cow;
+    use std::ffi::NulError;
+    use std::path::{Path, PathBuf};
+
+    #[anyinput]
+    fn total_len(names: AnyArray<AnyString>) -> usize {
+        names.iter().map(|name| name.as_ref().len()).sum()
+    }
+    let owned = "bc".to_string();
+    assert_eq!(total_len(args!["a", owned.clone(), &owned]), 5);
+
+    #[anyinput]
+    fn count_beds(paths: AnyIter<AnyPath>) -> usize {
+        paths
+            .filter(|path| path.as_ref().extension() == Some("bed".as_ref()))
+            .count()
+    }
+    assert_eq!(count_beds(args!["a.bed", "b.txt".to_string()]), 1);
+    assert_eq!(
+        count_beds(args![Path::new("a.bed"), PathBuf::from("b.bed")]),
+        2
+    );
+
+    #[anyinput]
+    fn byte_and_os_len(names: AnyArray<AnyBytes>, paths: AnyArray<AnyOsStr>) -> usize {
+        names.iter().map(|name| name.as_ref().len()).sum::<usize>()
+            + paths.iter().map(|path| path.as_ref().len()).sum::<usize>()
+    }
+    assert_eq!(
+        byte_and_os_len(args!["a", owned.clone()], args![PathBuf::from("de")]),
+        5
+    );
+
+    #[anyinput]
+    fn c_len(names: AnyIter<AnyCStr>) -> Result<usize, NulError> {
+        let mut total = 0;
+        for name in names {
+            total += name.to_c_str()?.to_bytes().len();
+        }
+        Ok(total)
+    }
+    assert_eq!(c_len(args!["a", owned.clone()])?, 3);
+
+    #[anyinput]
+    fn borrowed_count(names: AnyIter<AnyCow<str>>) -> usize {
+        names
+            .map(|name| name.into())
+            .filter(|name| matches!(name, Cow::Borrowed(_)))
+            .count()
+    }
+    assert_eq!(borrowed_count(args!["a", owned.clone()]), 1);
+
+    #[anyinput]
+    fn owned_names(names: AnyIter<AnyInto<String>>) -> Vec<String> {
+        names.map(|name| name.into()).collect()
+    }
+    assert_eq!(owned_names(args!["a", owned.clone()]), ["a", "bc"]);
+
+    #[anyinput]
+    fn count_all(names: AnyOneOrMany<AnyString>) -> usize {
+        names.count()
+    }
+    assert_eq!(count_all(args!["a", owned]), 2);
+    let empty: Vec<Arg<str>> = args![];
+    assert_eq!(count_all(empty), 0);
+
+    #[anyinput]
+    fn total_str_len(names: AnyIterBorrow<str>) -> usize {
+        names.map(|name| name.len()).sum()
+    }
+    let names = args!["a", "bc".to_string()];
+    assert_eq!(total_str_len(&names), 3);
+    assert_eq!(total_str_len(names.clone()), 3);
+
+    #[anyinput]
+    fn longest_len(names: AnyIterMulti<str>) -> usize {
+        let max = names.iter().map(|name| name.len()).max().unwrap_or(0);
+        names.iter().filter(|name| name.len() == max).count() * max
+    }
+    assert_eq!(longest_len(&names), 2);
+
+    #[anyinput]
+    fn has_name<'a>(names: AnySet<Arg<'a, str>>, name: &'a str) -> bool {
+        names.contains(&Arg::new(name))
+    }
+    assert!(has_name(names.clone(), "bc"));
+    assert!(has_name(&names, "a"));
+    assert!(!has_name(names, "d"));
+
+    #[anyinput]
+    fn total_ms(timeouts: AnyIter<AnyDuration>) -> Result<u128, anyinput::DurationError> {
+        let mut total = 0;
+        for timeout in timeouts {
+            total += timeout.to_duration()?.as_millis();
+        }
+        Ok(total)
+    }
+    assert_eq!(total_ms(args!["1s", "250ms".to_string()])?, 1250);
+
+    #[anyinput]
+    fn messages(errors: AnyIter<AnyError>) -> Vec<String> {
+        errors.map(|error| error.into().to_string()).collect()
+    }
+    assert_eq!(
+        messages(args!["not found", "empty".to_string()]),
+        ["not found", "empty"]
+    );
+
+    #[anyinput]
+    fn ports(addrs: AnyIter<AnySocketAddr>) -> Result<Vec<u16>, std::io::Error> {
+        let mut ports = vec![];
+        for addr in addrs {
+            ports.extend(addr.to_socket_addrs()?.map(|addr| addr.port()));
+        }
+        Ok(ports)
+    }
+    assert_eq!(
+        ports(args!["127.0.0.1:80", "127.0.0.1:8080".to_string()])?,
+        [80, 8080]
+    );
+
+    let names: std::collections::HashSet<Arg<str>> =
+        args!["a", "b".to_string()].into_iter().collect();
+    assert!(names.contains("b"));
+    assert_eq!(format!("{}", Arg::<str>::new("a")), "a");
+    Ok(())
+}
+
+#[test]
+fn display_input() {
+    use std::borrow::Cow;
+    use std::fmt;
+
+    enum Level {
+        Info,
+    }
+    impl fmt::Display for Level {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Level::Info => write!(f, "info"),
+            }
+        }
+    }
+
+    #[anyinput]
+    fn label(name: AnyDisplay, value: AnyDisplay) -> String {
+        format!("{name}={value}")
+    }
+    assert_eq!(label("width", 5), "width=5");
+    assert_eq!(label('x', 1.5), "x=1.5");
+    assert_eq!(label(Level::Info, "ok".to_string()), "info=ok");
+
+    #[anyinput]
+    fn is_borrowed(label: AnyDisplay) -> bool {
+        matches!(label, Cow::Borrowed(_))
+    }
+    let owned = "abc".to_string();
+    assert!(is_borrowed("abc"));
+    assert!(is_borrowed(&owned));
+    assert!(is_borrowed(Cow::Borrowed("abc")));
+    assert!(is_borrowed(owned));
+    assert!(!is_borrowed(5));
+    assert!(!is_borrowed(Level::Info));
+
+    #[anyinput]
+    fn join(labels: AnyIter<AnyDisplay>) -> String {
+        let labels: Vec<_> = labels
+            .map(|label| anyinput::display_to_cow(&label).into_owned())
+            .collect();
+        labels.join(",")
+    }
+    assert_eq!(join([1, 2]), "1,2");
+    assert_eq!(join(anyinput::args!["a", "b".to_string()]), "a,b");
+}
+
+// The display_to_cow tests below check each borrowed type, so that CI can run them under Miri with
+// "cargo miri test --test integration_test display_to_cow".
+#[allow(clippy::ptr_arg)]
+fn borrows_from(cow: &std::borrow::Cow<str>, text: &str) -> bool {
+    matches!(cow, std::borrow::Cow::Borrowed(s) if std::ptr::eq(s.as_ptr(), text.as_ptr()))
+}
+
+#[test]
+fn display_to_cow_str() {
+    let owned = "abc".to_string();
+    let text: &str = &owned;
+    let cow = anyinput::display_to_cow(text);
+    assert!(borrows_from(&cow, &owned));
+    assert_eq!(cow, "abc");
+}
+
+#[test]
+fn display_to_cow_ref_str() {
+    let owned = "abc".to_string();
+    let text: &str = &owned;
+    let cow = anyinput::display_to_cow(&text);
+    assert!(borrows_from(&cow, &owned));
+    assert_eq!(cow, "abc");
+}
+
+#[test]
+fn display_to_cow_string() {
+    let owned = "abc".to_string();
+    let cow = anyinput::display_to_cow(&owned);
+    assert!(borrows_from(&cow, &owned));
+    assert_eq!(cow, "abc");
+}
+
+#[test]
+fn display_to_cow_ref_string() {
+    let owned = "abc".to_string();
+    let owned_ref = &owned;
+    let cow = anyinput::display_to_cow(&owned_ref);
+    assert!(borrows_from(&cow, &owned));
+    assert_eq!(cow, "abc");
+}
+
+#[test]
+fn display_to_cow_cow() {
+    use std::borrow::Cow;
+
+    let owned = "abc".to_string();
+    let borrowed: Cow<str> = Cow::Borrowed(&owned);
+    let cow = anyinput::display_to_cow(&borrowed);
+    assert!(borrows_from(&cow, &owned));
+    let owned_cow: Cow<str> = Cow::Owned(owned);
+    let cow = anyinput::display_to_cow(&owned_cow);
+    assert!(borrows_from(&cow, &owned_cow));
+}
+
+#[test]
+fn display_to_cow_other() {
+    use std::borrow::Cow;
+
+    // Types that are not, up to lifetimes, one of the borrowed types are formatted.
+    let owned = "abc".to_string();
+    let text: &str = &owned;
+    assert!(matches!(anyinput::display_to_cow(&&text), Cow::Owned(s) if s == "abc"));
+    assert!(matches!(anyinput::display_to_cow(&'x'), Cow::Owned(s) if s == "x"));
+    assert!(
+        matches!(anyinput::display_to_cow(&anyinput::Arg::<str>::new(text)), Cow::Owned(s) if s == "abc")
+    );
+}
+
+#[test]
+fn duration_input() -> Result<(), anyhow::Error> {
+    use anyinput::DurationError;
+    use std::time::Duration;
+
+    #[anyinput]
+    fn timeout(timeout: AnyDuration) -> Result<Duration, DurationError> {
+        Ok(timeout)
+    }
+    assert_eq!(timeout(Duration::from_millis(5))?, Duration::from_millis(5));
+    assert_eq!(timeout(2u64)?, Duration::from_secs(2));
+    assert_eq!(timeout(2u32)?, Duration::from_secs(2));
+    assert_eq!(timeout(2)?, Duration::from_secs(2));
+    assert_eq!(timeout(-2i64), Err(DurationError::OutOfRange));
+    assert_eq!(timeout(0.25)?, Duration::from_millis(250));
+    assert_eq!(timeout("250ms")?, Duration::from_millis(250));
+    assert_eq!(timeout("1.5s".to_string())?, Duration::from_millis(1500));
+    assert_eq!(timeout(" 2 min ")?, Duration::from_secs(120));
+    assert_eq!(timeout("1h")?, Duration::from_secs(3600));
+    assert_eq!(timeout("10us")?, Duration::from_micros(10));
+    assert_eq!(timeout("10µs")?, Duration::from_micros(10));
+    assert_eq!(timeout("7ns")?, Duration::from_nanos(7));
+    assert_eq!(timeout("5")?, Duration::from_secs(5));
+    assert_eq!(
+        timeout("soon"),
+        Err(DurationError::Parse {
+            text: "soon".to_string()
+        })
+    );
+    assert!(timeout("5 weeks").is_err());
+    assert!(timeout("").is_err());
+    assert_eq!(timeout(-1.0), Err(DurationError::OutOfRange));
+    assert_eq!(timeout("-1s"), Err(DurationError::OutOfRange));
+    assert_eq!(timeout(f64::NAN), Err(DurationError::OutOfRange));
+
+    // The error converts into the function's error type with '?'.
+    #[anyinput]
+    fn total(timeouts: AnyArray<AnyDuration>) -> Result<Duration, anyhow::Error> {
+        let mut total = Duration::ZERO;
+        for timeout in timeouts.iter() {
+            total += timeout.to_duration()?;
+        }
+        Ok(total)
+    }
+    assert_eq!(total(["1s", "500ms"])?, Duration::from_millis(1500));
+    assert_eq!(
+        total(["1s", "later"]).unwrap_err().to_string(),
+        "invalid duration 'later', expected a number and an optional unit, for example, '250ms' or '1.5s'"
+    );
+
+    // An alias of Result works as the return type.
+    type Res<T> = Result<T, Box<dyn std::error::Error>>;
+    #[anyinput]
+    fn millis(timeout: AnyDuration) -> Res<u128> {
+        Ok(timeout.as_millis())
+    }
+    assert_eq!(millis("1.5s").unwrap(), 1500);
+    assert!(millis("soon").is_err());
+    Ok(())
+}
+
+#[test]
+fn arc_inputs() {
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
+
+    #[anyinput]
+    fn share(name: AnyArc<str>) -> Arc<str> {
+        name
+    }
+    assert_eq!(&*share("a"), "a");
+    assert_eq!(&*share("b".to_string()), "b");
+    assert_eq!(&*share(Box::<str>::from("c")), "c");
+    let shared: Arc<str> = Arc::from("d");
+    assert!(Arc::ptr_eq(&share(shared.clone()), &shared));
+
+    #[anyinput]
+    fn share_path(path: AnyArc<Path>) -> Arc<Path> {
+        path
+    }
+    assert_eq!(&*share_path(Path::new("a")), Path::new("a"));
+    assert_eq!(&*share_path(PathBuf::from("b")), Path::new("b"));
+
+    #[anyinput]
+    fn share_all(values: AnyArc<[u8]>) -> Arc<[u8]> {
+        values
+    }
+    assert_eq!(&*share_all(vec![1, 2]), [1, 2]);
+    assert_eq!(&*share_all([1, 2]), [1, 2]);
+    assert_eq!(&*share_all([1, 2].as_slice()), [1, 2]);
+
+    #[anyinput]
+    fn share_each(names: AnyIter<AnyArc<str>>) -> Vec<Arc<str>> {
+        names.map(|name| name.into()).collect()
+    }
+    assert_eq!(share_each(["a", "b"]).len(), 2);
+    let names = share_each(anyinput::args!["a", "b".to_string()]);
+    assert_eq!(
+        names.iter().map(|name| &**name).collect::<Vec<_>>(),
+        ["a", "b"]
+    );
+
+    #[anyinput]
+    fn share_each_path(paths: AnyIter<AnyArc<Path>>) -> Vec<Arc<Path>> {
+        paths.map(|path| path.into()).collect()
+    }
+    let paths = share_each_path(anyinput::args![Path::new("a"), PathBuf::from("b")]);
+    assert_eq!(&*paths[1], Path::new("b"));
+}
+
+#[test]
+fn error_input() {
+    use std::error::Error;
+    use std::io;
+    use std::num::ParseIntError;
+
+    #[anyinput]
+    fn describe(error: AnyError) -> String {
+        format!("failed: {error}")
+    }
+    let io_error = io::Error::new(io::ErrorKind::NotFound, "no file");
+    assert_eq!(describe(io_error), "failed: no file");
+    let parse_error: ParseIntError = "x".parse::<u8>().unwrap_err();
+    assert_eq!(
+        describe(parse_error.clone()),
+        "failed: invalid digit found in string"
+    );
+    assert_eq!(describe("bad input".to_string()), "failed: bad input");
+    assert_eq!(describe("bad input"), "failed: bad input");
+
+    // The boxed error keeps its type, so it can be downcast.
+    #[anyinput]
+    fn boxed(error: AnyError) -> Box<dyn Error + Send + Sync> {
+        error
+    }
+    assert!(boxed(parse_error).downcast_ref::<ParseIntError>().is_some());
+    let io_error = io::Error::other("busy");
+    assert!(boxed(io_error).downcast_ref::<io::Error>().is_some());
+
+    #[anyinput]
+    fn first_error(errors: AnyIter<AnyError>) -> Option<String> {
+        errors.map(|error| error.into().to_string()).next()
+    }
+    assert_eq!(
+        first_error(vec!["a".to_string(), "b".to_string()]),
+        Some("a".to_string())
+    );
+}
+
+#[cfg(feature = "anyhow")]
+#[test]
+fn anyhow_input() {
+    use std::error::Error;
+    use std::io;
+    use std::num::ParseIntError;
+
+    #[anyinput]
+    fn wrap(error: AnyAnyhow) -> anyhow::Error {
+        error.context("while loading")
+    }
+    let error = wrap(io::Error::new(io::ErrorKind::NotFound, "no file"));
+    assert_eq!(format!("{error:#}"), "while loading: no file");
+    let error = wrap("x".parse::<u8>().unwrap_err());
+    // anyhow keeps the error boxed, so downcasting goes through the box.
+    let boxed = error
+        .downcast_ref::<Box<dyn Error + Send + Sync>>()
+        .unwrap();
+    assert!(boxed.downcast_ref::<ParseIntError>().is_some());
+    let error = wrap("bad input".to_string());
+    assert_eq!(format!("{error:#}"), "while loading: bad input");
+    let error = wrap(anyinput::Arg::<str>::new("bad arg"));
+    assert_eq!(format!("{error:#}"), "while loading: bad arg");
+}
+
+#[test]
+fn socket_addr_input() -> Result<(), anyhow::Error> {
+    use std::io;
+    use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+
+    #[anyinput]
+    fn resolve(addr: AnySocketAddr) -> Result<Vec<SocketAddr>, io::Error> {
+        Ok(addr)
+    }
+    let loopback = SocketAddr::from((Ipv4Addr::LOCALHOST, 8080));
+    assert_eq!(resolve("127.0.0.1:8080")?, [loopback]);
+    assert_eq!(resolve("127.0.0.1:8080".to_string())?, [loopback]);
+    assert_eq!(resolve(("127.0.0.1", 8080))?, [loopback]);
+    assert_eq!(resolve((Ipv4Addr::LOCALHOST, 8080))?, [loopback]);
+    assert_eq!(resolve(loopback)?, [loopback]);
+    let addrs = resolve(("localhost", 8080))?;
+    assert!(!addrs.is_empty());
+    assert!(addrs
+        .iter()
+        .all(|addr| addr.ip().is_loopback() && addr.port() == 8080));
+    let error = resolve("127.0.0.1").unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+
+    // A loopback server and client that accept any address.
+    #[anyinput]
+    fn connect(addr: AnySocketAddr) -> Result<TcpStream, anyhow::Error> {
+        Ok(TcpStream::connect(&addr[..])?)
+    }
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let server_addr = listener.local_addr()?;
+    let stream = connect(server_addr)?;
+    assert_eq!(stream.peer_addr()?, server_addr);
+    let stream = connect(("127.0.0.1", server_addr.port()))?;
+    assert_eq!(stream.peer_addr()?, server_addr);
+    Ok(())
+}
+
+#[test]
+fn c_str_input() -> Result<(), anyhow::Error> {
+    use std::borrow::Cow;
+    use std::ffi::{CStr, CString, NulError};
+
+    #[anyinput]
+    fn c_len(s: AnyCStr) -> Result<usize, NulError> {
+        Ok(s.to_bytes().len())
+    }
+
+    assert_eq!(c_len(c"hello")?, 5);
+    let c_string = CString::new("hello")?;
+    assert_eq!(c_len(&c_string)?, 5);
+    assert_eq!(c_len(c_string)?, 5);
+    assert_eq!(c_len("hello")?, 5);
+    let string = "hello".to_string();
+    assert_eq!(c_len(&string)?, 5);
+    assert_eq!(c_len(string)?, 5);
+    assert_eq!(c_len("hel\0lo").unwrap_err().nul_position(), 3);
+
+    // A borrowed CStr stays borrowed.
+    #[anyinput]
+    fn is_borrowed(s: AnyCStr) -> Result<bool, NulError> {
+        Ok(matches!(s, Cow::Borrowed(_)))
+    }
+    assert!(is_borrowed(c"hello")?);
+    assert!(!is_borrowed("hello")?);
+
+    // The error converts with '?', for example, into an anyhow::Error.
+    #[anyinput]
+    fn joined(names: AnyIter<AnyCStr>) -> anyhow::Result<Vec<u8>> {
+        let mut result = Vec::new();
+        for name in names {
+            result.extend_from_slice(name.to_c_str()?.to_bytes());
+        }
+        Ok(result)
+    }
+    assert_eq!(joined(["a", "b"])?, b"ab");
+    let c_strs: Vec<&CStr> = vec![c"a", c"b"];
+    assert_eq!(joined(c_strs)?, b"ab");
+    assert!(joined(["a", "\0"]).is_err());
+    Ok(())
+}
+
 #[test]
 fn doc_path() -> Result<(), anyhow::Error> {
     use anyinput::anyinput;
@@ -0,0 +1,8 @@
+use anyinput::anyinput;
+
+#[anyinput]
+fn len(name: AnyArc) -> usize {
+    name.len()
+}
+
+fn main() {}
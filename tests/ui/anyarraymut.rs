@@ -0,0 +1,8 @@
+use anyinput::anyinput;
+
+#[anyinput]
+fn fill(array: AnyArrayMut, value: usize) {
+    array.fill(value);
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use anyinput::anyinput;
+
+#[anyinput]
+fn checksum(bytes: AnyBytes<u8>) -> u32 {
+    bytes.iter().map(|&b| b as u32).sum()
+}
+
+fn main() {}
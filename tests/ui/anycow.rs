@@ -0,0 +1,8 @@
+use anyinput::anyinput;
+
+#[anyinput]
+fn len(s: AnyCow) -> usize {
+    s.len()
+}
+
+fn main() {}
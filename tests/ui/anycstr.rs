@@ -0,0 +1,8 @@
+use anyinput::anyinput;
+
+#[anyinput]
+fn print_len(s: AnyCStr) {
+    println!("{}", s.to_bytes().len());
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use anyinput::anyinput;
+
+#[anyinput]
+fn c_len(s: AnyCStr<u8>) -> Result<usize, std::ffi::NulError> {
+    Ok(s.to_bytes().len())
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use anyinput::anyinput;
+
+#[anyinput]
+fn label(name: AnyDisplay<usize>) -> String {
+    name.to_string()
+}
+
+fn main() {}
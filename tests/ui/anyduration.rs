@@ -0,0 +1,8 @@
+use anyinput::anyinput;
+
+#[anyinput]
+fn print_ms(timeout: AnyDuration) {
+    println!("{}", timeout.as_millis());
+}
+
+fn main() {}
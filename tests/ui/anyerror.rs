@@ -0,0 +1,8 @@
+use anyinput::anyinput;
+
+#[anyinput]
+fn describe(error: AnyError<String>) -> String {
+    error.to_string()
+}
+
+fn main() {}
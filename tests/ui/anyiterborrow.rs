@@ -0,0 +1,8 @@
+use anyinput::anyinput;
+
+#[anyinput]
+fn total(iter: AnyIterBorrow) -> usize {
+    iter.map(|x| *x).sum()
+}
+
+fn main() {}
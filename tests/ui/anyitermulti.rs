@@ -0,0 +1,8 @@
+use anyinput::anyinput;
+
+#[anyinput]
+fn total(data: AnyIterMulti) -> usize {
+    data.iter().sum()
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use anyinput::anyinput;
+
+#[anyinput]
+fn count(map: AnyMap<AnyString, usize, ExactSizeIterator, Clone>) -> usize {
+    map.len()
+}
+
+fn main() {}
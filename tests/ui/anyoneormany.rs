@@ -0,0 +1,8 @@
+use anyinput::anyinput;
+
+#[anyinput]
+fn count(paths: AnyOneOrMany) -> usize {
+    paths.count()
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use anyinput::anyinput;
+
+#[anyinput]
+fn row_count(max_rows: AnyOption) -> usize {
+    max_rows.unwrap_or(100)
+}
+
+fn main() {}
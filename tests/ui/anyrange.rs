@@ -0,0 +1,8 @@
+use anyinput::anyinput;
+
+#[anyinput]
+fn sum(data: AnyArray<u32>, range: AnyRange) -> Result<u32, anyinput::RangeError> {
+    Ok(data[range].iter().sum())
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use anyinput::anyinput;
+
+#[anyinput]
+fn has_one(ids: AnySet) -> bool {
+    ids.contains(&1)
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use anyinput::anyinput;
+
+#[anyinput]
+fn print_port(addr: AnySocketAddr) {
+    println!("{}", addr[0].port());
+}
+
+fn main() {}
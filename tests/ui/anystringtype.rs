@@ -0,0 +1,8 @@
+use anyinput::anyinput;
+
+#[anyinput]
+pub fn any_str_len(s: AnyString<String>) -> usize {
+    s.len()
+}
+
+fn main() {}
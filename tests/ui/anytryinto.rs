@@ -0,0 +1,8 @@
+use anyinput::anyinput;
+
+#[anyinput]
+fn port(x: AnyTryInto<u16>) -> u16 {
+    x
+}
+
+fn main() {}
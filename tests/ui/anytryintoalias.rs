@@ -0,0 +1,8 @@
+use anyinput::anyinput;
+
+#[anyinput]
+fn port(x: AnyTryInto<u16>) -> std::io::Result<u16> {
+    Ok(x)
+}
+
+fn main() {}
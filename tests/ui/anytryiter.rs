@@ -0,0 +1,8 @@
+use anyinput::anyinput;
+
+#[anyinput]
+fn total(data: AnyTryIter<usize>) -> Result<usize, String> {
+    data.sum()
+}
+
+fn main() {}
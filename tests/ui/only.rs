@@ -0,0 +1,8 @@
+use anyinput::anyinput;
+
+#[anyinput(only(AnyIter, AnyStr))]
+fn total_len(strings: AnyIter<AnyString>) -> usize {
+    strings.map(|s| s.as_ref().len()).sum()
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use anyinput::anyinput;
+
+#[anyinput]
+fn first_len<AnyString: AsRef<str>>(strings: AnyIter<AnyString>) -> usize {
+    strings.next().map_or(0, |s| s.as_ref().len())
+}
+
+fn main() {}